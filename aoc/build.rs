@@ -0,0 +1,41 @@
+//! Hashes the sources of every day crate the solvers are built from into
+//! `OUT_DIR/source_hashes.rs`, so that cached answers are keyed on what was
+//! compiled into this binary rather than on the working tree.
+
+use std::{env, fs, path::Path};
+
+#[path = "src/sources.rs"]
+mod sources;
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = Path::new(&crate_dir).join("..");
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/sources.rs");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    for krate in sources::SHARED {
+        rerun_if_changed(krate);
+    }
+
+    let mut hashes = String::from("&[\n");
+    for number in 1..=25 {
+        let day = format!("day-{number:02}");
+
+        if !root.join(&day).join("Cargo.toml").is_file() {
+            continue;
+        }
+
+        rerun_if_changed(&day);
+        hashes += &format!("    ({number}, {:?}),\n", sources::hash(&root, &day));
+    }
+    hashes += "]\n";
+
+    fs::write(Path::new(&out_dir).join("source_hashes.rs"), hashes).unwrap();
+}
+
+/// Watches a crate's manifest and sources, but not its `target` directory.
+fn rerun_if_changed(krate: &str) {
+    println!("cargo:rerun-if-changed=../{krate}/Cargo.toml");
+    println!("cargo:rerun-if-changed=../{krate}/src");
+}
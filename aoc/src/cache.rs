@@ -0,0 +1,170 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+/// Answers `aoc run` has found, in a SQLite database under the root, so that
+/// re-running a part whose input and solver are unchanged is instant.
+pub struct Cache {
+    connection: Connection,
+    reuse: bool,
+}
+
+/// What a cached answer is stored under: the part, what it was given and
+/// what solved it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    /// See [`source_hash`].
+    pub source_hash: String,
+}
+
+/// A cached answer, with how long finding it took the first time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+impl Cache {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".aoc").join("cache.sqlite3")
+    }
+
+    /// The cache under `root`. Unless `reuse`, every lookup misses, so that
+    /// answers are found afresh and the cache refreshed with them.
+    pub fn open(root: &Path, reuse: bool) -> rusqlite::Result<Cache> {
+        let path = Cache::path(root);
+
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+
+        Cache::with(Connection::open(path)?, reuse)
+    }
+
+    fn with(connection: Connection, reuse: bool) -> rusqlite::Result<Cache> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS answers (
+                day INTEGER NOT NULL,
+                part INTEGER NOT NULL,
+                input_hash TEXT NOT NULL,
+                source_hash TEXT NOT NULL,
+                answer TEXT NOT NULL,
+                elapsed_us INTEGER NOT NULL,
+                PRIMARY KEY (day, part, input_hash, source_hash)
+            );",
+        )?;
+
+        Ok(Cache { connection, reuse })
+    }
+
+    pub fn get(&self, key: &Key) -> rusqlite::Result<Option<Hit>> {
+        if !self.reuse {
+            return Ok(None);
+        }
+
+        self.connection
+            .query_row(
+                "SELECT answer, elapsed_us FROM answers
+                    WHERE day = ?1 AND part = ?2 AND input_hash = ?3 AND source_hash = ?4",
+                params![key.day, key.part, key.input_hash, key.source_hash],
+                |row| {
+                    Ok(Hit {
+                        answer: row.get(0)?,
+                        elapsed: Duration::from_micros(row.get::<_, i64>(1)? as u64),
+                    })
+                },
+            )
+            .optional()
+    }
+
+    pub fn put(&self, key: &Key, hit: &Hit) -> rusqlite::Result<()> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO answers
+                (day, part, input_hash, source_hash, answer, elapsed_us)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                key.day,
+                key.part,
+                key.input_hash,
+                key.source_hash,
+                hit.answer,
+                hit.elapsed.as_micros() as i64,
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+/// The hash of the sources day `day`'s solvers were compiled from, taken by
+/// the build script with `sources::hash`, or `None` for a day that was not
+/// there when this binary was built.
+pub fn source_hash(day: u8) -> Option<&'static str> {
+    SOURCE_HASHES
+        .iter()
+        .find(|(number, _)| *number == day)
+        .map(|(_, hash)| *hash)
+}
+
+const SOURCE_HASHES: &[(u8, &str)] = include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day::Day, sources};
+
+    #[test]
+    fn finds_answers_by_key() {
+        let cache = Cache::with(Connection::open_in_memory().unwrap(), true).unwrap();
+        let key = Key {
+            day: 6,
+            part: 1,
+            input_hash: "abc".to_string(),
+            source_hash: "def".to_string(),
+        };
+        let hit = Hit {
+            answer: "288".to_string(),
+            elapsed: Duration::from_micros(30),
+        };
+
+        assert_eq!(cache.get(&key).unwrap(), None);
+
+        cache.put(&key, &hit).unwrap();
+        assert_eq!(cache.get(&key).unwrap(), Some(hit.clone()));
+
+        let changed = Key {
+            source_hash: "fed".to_string(),
+            ..key.clone()
+        };
+        assert_eq!(cache.get(&changed).unwrap(), None);
+
+        let refreshing = Cache {
+            reuse: false,
+            ..cache
+        };
+        assert_eq!(refreshing.get(&key).unwrap(), None);
+    }
+
+    #[test]
+    fn keys_on_the_sources_this_binary_was_built_from() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        for day in Day::all(&root) {
+            assert_eq!(
+                source_hash(day.number),
+                Some(sources::hash(&root, &day.name()).as_str()),
+                "{}",
+                day.name()
+            );
+        }
+
+        assert_eq!(source_hash(25), None);
+    }
+}
@@ -0,0 +1,186 @@
+use std::{
+    fs, io,
+    process::{Command, ExitStatus, Output},
+    time::{Duration, Instant},
+};
+
+use crate::day::Day;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TestReport {
+    pub passed: usize,
+    pub failed: Vec<String>,
+    /// Whether cargo managed to build and run the tests at all.
+    pub built: bool,
+    pub stderr: String,
+}
+
+impl TestReport {
+    pub fn is_success(&self) -> bool {
+        self.built && self.failed.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct RunReport {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub stderr: String,
+}
+
+/// Runs every example test of a day, without stopping at the first failing binary.
+pub fn test(day: &Day) -> io::Result<TestReport> {
+    let output = cargo(day, &["test", "--no-fail-fast"]).output()?;
+    let mut report = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+
+    report.stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    Ok(report)
+}
+
+/// Builds the release binaries of a day so that `run` only measures the solver.
+pub fn build(day: &Day) -> io::Result<Output> {
+    cargo(day, &["build", "--release", "--bins", "--quiet"]).output()
+}
+
+/// Runs one part against the real input. A non-zero `verbosity` turns on the
+/// solver's logging, which ends up in the report's stderr.
+pub fn run(day: &Day, part: u8, verbosity: u8) -> io::Result<RunReport> {
+    let bin = format!("part{part}");
+    let mut command = cargo(day, &["run", "--release", "--quiet", "--bin", &bin]);
+
+    if let Some(level) = log_level(verbosity) {
+        command.env(common::logging::ENV_VAR, level);
+    }
+
+    let start = Instant::now();
+    let output = command.output()?;
+    let elapsed = start.elapsed();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    let answer = if output.status.success() {
+        stdout.lines().last().map(|line| line.trim().to_string())
+    } else {
+        None
+    };
+
+    Ok(RunReport {
+        answer,
+        elapsed,
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+/// Fuzzes one part with cargo-fuzz, starting from the seeds taken from the
+/// example tests. New inputs are kept in `fuzz/corpus` and crashes in
+/// `fuzz/artifacts`. Needs a nightly toolchain and `cargo install cargo-fuzz`.
+pub fn fuzz(day: &Day, part: u8, seconds: Option<u64>) -> io::Result<ExitStatus> {
+    let target = format!("part{part}");
+    let corpus = day.fuzz().join("corpus").join(&target);
+    let seeds = day.fuzz().join("seeds").join(&target);
+
+    // libFuzzer only writes into a corpus directory that already exists
+    fs::create_dir_all(&corpus)?;
+
+    let mut command = Command::new("cargo");
+    command
+        .args(["+nightly", "fuzz", "run", &target])
+        .arg(corpus)
+        .arg(seeds)
+        .current_dir(&day.dir);
+
+    if let Some(seconds) = seconds {
+        command.arg("--").arg(format!("-max_total_time={seconds}"));
+    }
+
+    command.status()
+}
+
+/// `-v` shows what each solver found, `-vv` how it got there and `-vvv` everything.
+pub fn log_level(verbosity: u8) -> Option<&'static str> {
+    match verbosity {
+        0 => None,
+        1 => Some("info"),
+        2 => Some("debug"),
+        _ => Some("trace"),
+    }
+}
+
+fn cargo(day: &Day, args: &[&str]) -> Command {
+    let mut command = Command::new(env!("CARGO"));
+    command
+        .args(args)
+        .arg("--manifest-path")
+        .arg(day.manifest());
+
+    command
+}
+
+fn parse_test_output(stdout: &str) -> TestReport {
+    let mut report = TestReport::default();
+
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("test ")
+            .and_then(|rest| rest.strip_suffix(" ... FAILED"))
+        {
+            report.failed.push(name.to_string());
+        }
+
+        if let Some(summary) = line.strip_prefix("test result: ") {
+            report.built = true;
+            report.passed += summary
+                .split(';')
+                .find_map(|part| part.trim().strip_suffix(" passed"))
+                .and_then(|count| count.rsplit(' ').next())
+                .and_then(|count| count.parse::<usize>().ok())
+                .unwrap_or(0);
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_libtest_output() {
+        let report = parse_test_output(
+            "
+running 2 tests
+test tests::it_works ... ok
+test tests::make_hand ... FAILED
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 3 tests
+test tests::it_works ... ok
+test tests::make_hand ... ok
+test tests::hand_order ... ok
+
+test result: ok. 3 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+",
+        );
+
+        assert_eq!(
+            report,
+            TestReport {
+                passed: 4,
+                failed: vec!["tests::make_hand".to_string()],
+                built: true,
+                stderr: String::new(),
+            }
+        );
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn no_summary_means_build_failed() {
+        let report = parse_test_output("");
+
+        assert!(!report.built);
+        assert!(!report.is_success());
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+/// A `day-NN` crate on disk.
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub number: u8,
+    pub dir: PathBuf,
+}
+
+impl Day {
+    pub fn find(root: &Path, number: u8) -> Option<Day> {
+        let dir = root.join(format!("day-{number:02}"));
+
+        if !dir.join("Cargo.toml").is_file() {
+            return None;
+        }
+
+        Some(Day { number, dir })
+    }
+
+    /// Every day crate under `root`, in calendar order.
+    pub fn all(root: &Path) -> Vec<Day> {
+        (1..=25)
+            .filter_map(|number| Day::find(root, number))
+            .collect()
+    }
+
+    pub fn name(&self) -> String {
+        format!("day-{:02}", self.number)
+    }
+
+    pub fn manifest(&self) -> PathBuf {
+        self.dir.join("Cargo.toml")
+    }
+
+    pub fn sources(&self) -> PathBuf {
+        self.dir.join("src")
+    }
+
+    /// The puzzle description, converted from the puzzle's page by
+    /// `aoc puzzle --import`.
+    pub fn puzzle(&self) -> PathBuf {
+        self.dir.join("PUZZLE.md")
+    }
+
+    /// The day's cargo-fuzz crate, with one target per part.
+    pub fn fuzz(&self) -> PathBuf {
+        self.dir.join("fuzz")
+    }
+
+    /// The input file the `partN` binary reads, as named by the day's
+    /// `INPUTS`, which may only exist sealed, see [`common::secret`]. Days
+    /// added since `aoc` was built have none.
+    pub fn input(&self, part: u8) -> Option<PathBuf> {
+        let file = solvers::input_file(self.number, part)?;
+
+        Some(self.sources().join("bin").join(file))
+    }
+
+    /// The `partN` binaries this day has, in order.
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=2)
+            .filter(|part| {
+                self.sources()
+                    .join("bin")
+                    .join(format!("part{part}.rs"))
+                    .is_file()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_day_crates() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let day = Day::find(&root, 7).unwrap();
+
+        assert_eq!(day.name(), "day-07");
+        assert_eq!(day.parts(), vec![1, 2]);
+        assert!(day.input(2).unwrap().ends_with("src/bin/input.txt"));
+        assert!(Day::find(&root, 2)
+            .unwrap()
+            .input(2)
+            .unwrap()
+            .ends_with("input1.txt"));
+        assert!(Day::find(&root, 10).is_none());
+
+        let all: Vec<u8> = Day::all(&root).iter().map(|day| day.number).collect();
+        assert_eq!(all, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 11]);
+    }
+}
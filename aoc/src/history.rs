@@ -0,0 +1,346 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rusqlite::{params, Connection};
+
+use crate::run::{Outcome, PartReport};
+
+/// Every part `aoc run` has solved or answered from its cache, in a SQLite
+/// database under the root. `watch` and `tui` can time whole cargo processes, so
+/// their runs are left out to keep the timings comparable.
+pub struct History {
+    connection: Connection,
+}
+
+/// One part run against one input at one commit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub commit: String,
+    /// `None` unless the part answered.
+    pub answer: Option<String>,
+    /// `answered`, `failed` or `timed out`.
+    pub outcome: String,
+    pub elapsed: Duration,
+    /// Whether the answer came from the answer cache rather than a solver, in
+    /// which case `elapsed` is how long finding it took the first time.
+    pub cached: bool,
+}
+
+/// An [`Entry`] as read back, with when it was recorded.
+#[derive(Debug)]
+pub struct Record {
+    pub recorded_at: String,
+    pub entry: Entry,
+}
+
+impl Entry {
+    /// The entry for a report, or `None` if its input could not be read.
+    pub fn from_report(report: &PartReport, commit: &str) -> Option<Entry> {
+        let answer = match &report.outcome {
+            Outcome::Answered(answer) => Some(answer.clone()),
+            _ => None,
+        };
+
+        Some(Entry {
+            day: report.day,
+            part: report.part,
+            input_hash: report.input_hash.clone()?,
+            commit: commit.to_string(),
+            answer,
+            outcome: report.outcome.name().to_string(),
+            elapsed: report.elapsed,
+            cached: report.cached,
+        })
+    }
+}
+
+impl History {
+    pub fn path(root: &Path) -> PathBuf {
+        root.join(".aoc").join("history.sqlite3")
+    }
+
+    pub fn open(root: &Path) -> rusqlite::Result<History> {
+        let path = History::path(root);
+
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+
+        History::with(Connection::open(path)?)
+    }
+
+    fn with(connection: Connection) -> rusqlite::Result<History> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY,
+                recorded_at INTEGER NOT NULL,
+                day INTEGER NOT NULL,
+                part INTEGER NOT NULL,
+                input_hash TEXT NOT NULL,
+                git_commit TEXT NOT NULL,
+                outcome TEXT NOT NULL,
+                answer TEXT,
+                elapsed_us INTEGER NOT NULL,
+                cached INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE INDEX IF NOT EXISTS runs_by_key
+                ON runs (day, part, input_hash, git_commit);",
+        )?;
+
+        // histories from before cache hits were recorded have no such column
+        if connection.prepare("SELECT cached FROM runs").is_err() {
+            connection
+                .execute_batch("ALTER TABLE runs ADD COLUMN cached INTEGER NOT NULL DEFAULT 0;")?;
+        }
+
+        Ok(History { connection })
+    }
+
+    /// Records every entry, warning on stderr instead of failing: the history
+    /// is a side effect of running, never a reason for a run to fail.
+    pub fn record_all(root: &Path, entries: impl IntoIterator<Item = Entry>) {
+        let result = History::open(root).and_then(|history| {
+            entries
+                .into_iter()
+                .try_for_each(|entry| history.record(&entry))
+        });
+
+        if let Err(error) = result {
+            eprintln!("could not record the run history: {error}");
+        }
+    }
+
+    pub fn record(&self, entry: &Entry) -> rusqlite::Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs() as i64);
+
+        self.connection.execute(
+            "INSERT INTO runs
+                (recorded_at, day, part, input_hash, git_commit, outcome, answer, elapsed_us,
+                    cached)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                now,
+                entry.day,
+                entry.part,
+                entry.input_hash,
+                entry.commit,
+                entry.outcome,
+                entry.answer,
+                entry.elapsed.as_micros() as i64,
+                entry.cached,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// A day's runs, oldest first.
+    pub fn day(&self, day: u8) -> rusqlite::Result<Vec<Record>> {
+        let mut statement = self.connection.prepare(
+            "SELECT datetime(recorded_at, 'unixepoch'), day, part, input_hash, git_commit,
+                    outcome, answer, elapsed_us, cached
+                FROM runs WHERE day = ?1 ORDER BY part, id",
+        )?;
+
+        let records = statement.query_map([day], |row| {
+            Ok(Record {
+                recorded_at: row.get(0)?,
+                entry: Entry {
+                    day: row.get(1)?,
+                    part: row.get(2)?,
+                    input_hash: row.get(3)?,
+                    commit: row.get(4)?,
+                    outcome: row.get(5)?,
+                    answer: row.get(6)?,
+                    elapsed: Duration::from_micros(row.get::<_, i64>(7)? as u64),
+                    cached: row.get(8)?,
+                },
+            })
+        })?;
+
+        records.collect()
+    }
+}
+
+/// The commit checked out in `root`, marked `-dirty` when there are
+/// uncommitted changes, or `unknown` outside a git repository.
+pub fn git_commit(root: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+
+    match git(&["status", "--porcelain"]) {
+        Some(changes) if !changes.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
+
+/// Prints a day's runs part by part, flagging answers that differ from the
+/// last run of the same part on the same input.
+pub fn print(records: &[Record]) {
+    let mut part = None;
+
+    for (Record { recorded_at, entry }, changed) in records.iter().zip(changes(records)) {
+        if part != Some(entry.part) {
+            println!("\npart{}", entry.part);
+            part = Some(entry.part);
+        }
+
+        let result = match &entry.answer {
+            Some(answer) if entry.cached => format!("{answer}  ({:.2?}, cached)", entry.elapsed),
+            Some(answer) => format!("{answer}  ({:.2?})", entry.elapsed),
+            None => entry.outcome.to_uppercase(),
+        };
+
+        println!(
+            "  {recorded_at}  {:<14}  input {}  {}{result}",
+            entry.commit,
+            &entry.input_hash[..8.min(entry.input_hash.len())],
+            if changed { "CHANGED " } else { "" },
+        );
+    }
+}
+
+/// Whether each record's answer differs from the one before it for the same
+/// part and input, however many runs on other inputs came in between.
+fn changes(records: &[Record]) -> Vec<bool> {
+    let mut last: HashMap<(u8, &str), &Option<String>> = HashMap::new();
+
+    records
+        .iter()
+        .map(|Record { entry, .. }| {
+            let key = (entry.part, entry.input_hash.as_str());
+
+            last.insert(key, &entry.answer)
+                .is_some_and(|answer| *answer != entry.answer)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(part: u8, answer: &str, micros: u64) -> Entry {
+        Entry {
+            day: 7,
+            part,
+            input_hash: "c0ffee".to_string(),
+            commit: "abc1234".to_string(),
+            answer: Some(answer.to_string()),
+            outcome: "answered".to_string(),
+            elapsed: Duration::from_micros(micros),
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn reads_back_a_days_runs() {
+        let history = History::with(Connection::open_in_memory().unwrap()).unwrap();
+        let runs = [
+            entry(2, "248909434", 900),
+            entry(1, "250474325", 800),
+            Entry {
+                cached: true,
+                ..entry(1, "250474325", 800)
+            },
+        ];
+
+        for run in &runs {
+            history.record(run).unwrap();
+        }
+        history
+            .record(&Entry {
+                day: 8,
+                ..entry(1, "17621", 10)
+            })
+            .unwrap();
+
+        let records = history.day(7).unwrap();
+        let entries: Vec<&Entry> = records.iter().map(|record| &record.entry).collect();
+
+        assert_eq!(entries, vec![&runs[1], &runs[2], &runs[0]]);
+        assert_eq!(records[0].recorded_at.len(), "2023-12-07 06:00:00".len());
+    }
+
+    #[test]
+    fn adds_the_cached_column_to_older_histories() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE runs (
+                    id INTEGER PRIMARY KEY,
+                    recorded_at INTEGER NOT NULL,
+                    day INTEGER NOT NULL,
+                    part INTEGER NOT NULL,
+                    input_hash TEXT NOT NULL,
+                    git_commit TEXT NOT NULL,
+                    outcome TEXT NOT NULL,
+                    answer TEXT,
+                    elapsed_us INTEGER NOT NULL
+                );
+                INSERT INTO runs VALUES (1, 0, 7, 1, 'c0ffee', 'abc1234', 'answered', '6440', 800);",
+            )
+            .unwrap();
+
+        let history = History::with(connection).unwrap();
+        history
+            .record(&Entry {
+                cached: true,
+                ..entry(1, "6440", 800)
+            })
+            .unwrap();
+
+        let cached: Vec<bool> = history
+            .day(7)
+            .unwrap()
+            .iter()
+            .map(|record| record.entry.cached)
+            .collect();
+        assert_eq!(cached, vec![false, true]);
+    }
+
+    #[test]
+    fn flags_changes_per_input() {
+        let record = |input_hash: &str, answer: &str| Record {
+            recorded_at: "2023-12-07 06:00:00".to_string(),
+            entry: Entry {
+                input_hash: input_hash.to_string(),
+                ..entry(1, answer, 800)
+            },
+        };
+        let records = [
+            record("alice", "1"),
+            record("bob", "2"),
+            record("alice", "1"),
+            record("bob", "3"),
+            record("alice", "4"),
+            Record {
+                entry: entry(2, "4", 900),
+                ..record("alice", "4")
+            },
+        ];
+
+        assert_eq!(changes(&records), [false, false, false, true, true, false]);
+    }
+}
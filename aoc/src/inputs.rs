@@ -0,0 +1,215 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use common::secret::{self, Key};
+
+use crate::{day::Day, profile::Profile};
+
+/// Every input in the tree, bundled or a profile's, by its plain text path
+/// whether it is stored plain, sealed or both.
+pub fn files(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let days = Day::all(root);
+    let mut files: Vec<PathBuf> = Profile::all(root)?
+        .iter()
+        .flat_map(|profile| {
+            days.iter().flat_map(move |day| {
+                day.parts()
+                    .into_iter()
+                    .filter_map(move |part| profile.input(day, part))
+            })
+        })
+        .collect();
+
+    files.sort();
+    files.dedup();
+
+    Ok(files)
+}
+
+/// The key to seal with, made and saved to [`Key::file`] if there is none yet.
+pub fn key_or_new() -> io::Result<Key> {
+    match Key::load() {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            let key = Key::generate();
+            let file = Key::file();
+
+            fs::create_dir_all(file.parent().expect("the key file is in .aoc/"))?;
+            fs::write(&file, key.to_hex())?;
+            println!(
+                "made a new input key in {}, share it with the team out of band",
+                file.display()
+            );
+
+            Ok(key)
+        }
+        key => key,
+    }
+}
+
+/// Seals every plain text input and deletes the plain text. A sealed copy
+/// that already holds the same text is kept, so unchanged inputs do not
+/// show up in `git status`.
+pub fn encrypt(files: &[PathBuf], key: &Key) -> io::Result<()> {
+    for plain in files.iter().filter(|file| file.is_file()) {
+        let text = fs::read(plain)?;
+        let sealed = secret::sealed_path(plain);
+        let current = fs::read(&sealed)
+            .ok()
+            .and_then(|sealed| secret::open(key, &sealed).ok());
+
+        if current.as_deref() != Some(&text[..]) {
+            fs::write(&sealed, secret::seal(key, &text))?;
+            println!("sealed {}", sealed.display());
+        }
+
+        fs::remove_file(plain)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a plain text copy of every sealed input, for editing or reading.
+pub fn decrypt(files: &[PathBuf], key: &Key) -> io::Result<()> {
+    for plain in files {
+        let sealed = secret::sealed_path(plain);
+
+        if !sealed.is_file() {
+            continue;
+        }
+
+        let text = secret::open(key, &fs::read(&sealed)?).map_err(|error| {
+            io::Error::new(error.kind(), format!("{}: {error}", sealed.display()))
+        })?;
+
+        fs::write(plain, text)?;
+        println!("decrypted {}", plain.display());
+    }
+
+    Ok(())
+}
+
+/// Where plain text inputs live, as in `.gitignore`, to catch the ones no
+/// binary or profile reads as well.
+const PLAIN_TEXT: [&str; 2] = [":(glob)day-*/src/bin/input*.txt", ":(glob)inputs/**/*.txt"];
+
+/// The inputs git tracks in plain text, and sealed files that are not sealed
+/// at all, each with why it is refused.
+pub fn check(root: &Path, files: &[PathBuf]) -> io::Result<Vec<String>> {
+    let tracked = Command::new("git")
+        .arg("ls-files")
+        .arg("--")
+        .args(files)
+        .args(PLAIN_TEXT)
+        .current_dir(root)
+        .output()?;
+
+    if !tracked.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&tracked.stderr).trim().to_string(),
+        ));
+    }
+
+    let mut refused: Vec<String> = String::from_utf8_lossy(&tracked.stdout)
+        .lines()
+        .map(|file| format!("{file}: committed in plain text"))
+        .collect();
+
+    for sealed in files.iter().map(|file| secret::sealed_path(file)) {
+        if sealed.is_file() && !secret::is_sealed(&fs::read(&sealed)?) {
+            refused.push(format!("{}: not actually sealed", sealed.display()));
+        }
+    }
+
+    Ok(refused)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seals_and_decrypts_every_input() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let bin = root.join("day-06/src/bin");
+        let input = "Time: 7\nDistance: 9\n";
+
+        fs::create_dir_all(&bin).unwrap();
+        fs::create_dir_all(root.join("inputs/ann")).unwrap();
+        fs::write(root.join("day-06/Cargo.toml"), "").unwrap();
+        fs::write(bin.join("part1.rs"), "fn main() {}").unwrap();
+        fs::write(bin.join("input1.txt"), input).unwrap();
+        fs::write(root.join("inputs/ann/day-06.txt"), input).unwrap();
+
+        let files = files(&root).unwrap();
+        assert_eq!(
+            files,
+            vec![bin.join("input1.txt"), root.join("inputs/ann/day-06.txt")]
+        );
+
+        let key = Key::generate();
+        encrypt(&files, &key).unwrap();
+
+        assert!(!bin.join("input1.txt").exists());
+        assert!(secret::read(&bin.join("input1.txt")).is_err());
+        assert_eq!(super::files(&root).unwrap(), files);
+
+        let sealed = fs::read(bin.join("input1.txt.enc")).unwrap();
+        decrypt(&files, &key).unwrap();
+        encrypt(&files, &key).unwrap();
+
+        assert_eq!(fs::read(bin.join("input1.txt.enc")).unwrap(), sealed);
+
+        decrypt(&files, &key).unwrap();
+        assert_eq!(secret::read(&bin.join("input1.txt")).unwrap(), input);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuses_plain_text_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-check-{}", std::process::id()));
+        let bin = root.join("day-02/src/bin");
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(&root)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?}");
+        };
+
+        fs::create_dir_all(&bin).unwrap();
+        git(&["init", "-q"]);
+        fs::write(bin.join("input2.txt"), "").unwrap();
+        fs::write(bin.join("input1.txt.enc"), "Game 1: 3 blue\n").unwrap();
+        git(&["add", "."]);
+
+        let refused = check(&root, &[bin.join("input1.txt")]).unwrap();
+        assert_eq!(
+            refused,
+            vec![
+                "day-02/src/bin/input2.txt: committed in plain text".to_string(),
+                format!(
+                    "{}: not actually sealed",
+                    bin.join("input1.txt.enc").display()
+                ),
+            ]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn the_tree_passes_its_own_check() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        assert_eq!(
+            check(&root, &files(&root).unwrap()).unwrap(),
+            Vec::<String>::new()
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::{collections::BTreeMap, fmt};
+
+use common::input::normalise;
+
+/// The integer types a solver might pick, narrowest first.
+const TYPES: [(&str, i128, i128); 4] = [
+    ("i32", i32::MIN as i128, i32::MAX as i128),
+    ("u32", 0, u32::MAX as i128),
+    ("i64", i64::MIN as i128, i64::MAX as i128),
+    ("u64", 0, u64::MAX as i128),
+];
+
+/// The shape of an input, to pick parsers and integer types by rather than
+/// guess.
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub lines: usize,
+    /// How many lines have each length, in characters.
+    pub lengths: BTreeMap<usize, usize>,
+    /// Every character but the newlines, with how often it appears.
+    pub alphabet: BTreeMap<char, usize>,
+    /// Rows and columns, when every line is as long as the first.
+    pub grid: Option<(usize, usize)>,
+    pub numbers: Numbers,
+}
+
+/// The runs of digits in an input, with a `-` in front making them negative
+/// unless it follows a letter or digit, as in `seed-to-soil`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Numbers {
+    pub count: usize,
+    /// `None` while there are no numbers.
+    pub range: Option<(i128, i128)>,
+    /// Whether a number was too long for even an `i128`.
+    pub huge: bool,
+}
+
+impl Numbers {
+    fn add(&mut self, number: Option<i128>) {
+        self.count += 1;
+
+        let Some(number) = number else {
+            self.huge = true;
+            return;
+        };
+
+        self.range = Some(match self.range {
+            Some((min, max)) => (min.min(number), max.max(number)),
+            None => (number, number),
+        });
+    }
+
+    /// The integer types that hold every number.
+    pub fn fits_in(&self) -> Vec<&'static str> {
+        let Some((min, max)) = self.range.filter(|_| !self.huge) else {
+            return match self.huge {
+                true => Vec::new(),
+                false => TYPES.iter().map(|(name, ..)| *name).collect(),
+            };
+        };
+
+        TYPES
+            .iter()
+            .filter(|(_, low, high)| *low <= min && max <= *high)
+            .map(|(name, ..)| *name)
+            .collect()
+    }
+}
+
+/// Summarises `input` as the days see it, normalised.
+pub fn inspect(input: &str) -> Summary {
+    let input = normalise(input);
+    let mut lengths = BTreeMap::new();
+    let mut alphabet = BTreeMap::new();
+    let mut numbers = Numbers::default();
+    let mut lines = 0;
+
+    for line in input.lines() {
+        lines += 1;
+        *lengths.entry(line.chars().count()).or_insert(0) += 1;
+
+        for char in line.chars() {
+            *alphabet.entry(char).or_insert(0) += 1;
+        }
+
+        let chars: Vec<char> = line.chars().collect();
+        let mut end = 0;
+        while let Some(start) = (end..chars.len()).find(|&at| chars[at].is_ascii_digit()) {
+            end = (start..chars.len())
+                .find(|&at| !chars[at].is_ascii_digit())
+                .unwrap_or(chars.len());
+
+            let negative = start >= 1
+                && chars[start - 1] == '-'
+                && !(start >= 2 && chars[start - 2].is_alphanumeric());
+            let digits: String = chars[start..end].iter().collect();
+            let number = digits
+                .parse::<i128>()
+                .ok()
+                .map(|number| if negative { -number } else { number });
+
+            numbers.add(number);
+        }
+    }
+
+    let grid = match lengths.len() {
+        1 => lengths.keys().next().map(|&columns| (lines, columns)),
+        _ => None,
+    };
+
+    Summary {
+        lines,
+        lengths,
+        alphabet,
+        grid,
+        numbers,
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "lines         {}", self.lines)?;
+
+        let lengths: Vec<String> = match self.lengths.len() {
+            0..=5 => self
+                .lengths
+                .iter()
+                .map(|(length, lines)| format!("{length} ×{lines}"))
+                .collect(),
+            _ => {
+                let mut sorted = self
+                    .lengths
+                    .iter()
+                    .flat_map(|(&length, &lines)| std::iter::repeat_n(length, lines));
+                let median = sorted.nth(self.lines / 2).unwrap_or(0);
+                let (min, max) = (self.lengths.keys().next(), self.lengths.keys().next_back());
+
+                vec![
+                    format!("min {}", min.unwrap_or(&0)),
+                    format!("median {median}"),
+                    format!("max {}", max.unwrap_or(&0)),
+                    format!("{} distinct", self.lengths.len()),
+                ]
+            }
+        };
+        writeln!(f, "line lengths  {}", lengths.join(", "))?;
+
+        let alphabet: String = self.alphabet.keys().collect();
+        writeln!(
+            f,
+            "alphabet      {alphabet:?} ({} characters)",
+            self.alphabet.len()
+        )?;
+
+        match self.grid {
+            Some((rows, columns)) => writeln!(f, "grid          {rows} × {columns}")?,
+            None => writeln!(f, "grid          no, the lines differ in length")?,
+        }
+
+        match (self.numbers.range, self.numbers.huge) {
+            (_, true) => writeln!(f, "numbers       {}, some beyond i128", self.numbers.count)?,
+            (Some((min, max)), false) => writeln!(
+                f,
+                "numbers       {}, from {min} to {max}",
+                self.numbers.count
+            )?,
+            (None, false) => writeln!(f, "numbers       none")?,
+        }
+
+        let fits = self.numbers.fits_in();
+        match fits.is_empty() {
+            true => writeln!(f, "fits in       none of {}", type_names().join(", ")),
+            false => writeln!(f, "fits in       {}", fits.join(", ")),
+        }
+    }
+}
+
+fn type_names() -> Vec<&'static str> {
+    TYPES.iter().map(|(name, ..)| *name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_grid() {
+        let summary = inspect("...#\r\n#...\r\n....\r\n\r\n");
+
+        assert_eq!(summary.lines, 3);
+        assert_eq!(summary.grid, Some((3, 4)));
+        assert_eq!(summary.alphabet.keys().collect::<String>(), "#.");
+        assert_eq!(summary.numbers.range, None);
+        assert_eq!(summary.numbers.fits_in(), type_names());
+    }
+
+    #[test]
+    fn ranges_the_numbers() {
+        let summary = inspect("seed-to-soil map:\n0 -3 4294967296\n10 13 16 21\n");
+
+        assert_eq!(summary.grid, None);
+        assert_eq!(summary.numbers.count, 7);
+        assert_eq!(summary.numbers.range, Some((-3, 4294967296)));
+        assert_eq!(summary.numbers.fits_in(), vec!["i64"]);
+
+        let huge = inspect("1 999999999999999999999999999999999999999999\n");
+        assert!(huge.numbers.huge);
+        assert!(huge.numbers.fits_in().is_empty());
+        assert!(huge.to_string().contains("some beyond i128"));
+    }
+
+    #[test]
+    fn describes_the_summary() {
+        assert_eq!(
+            inspect("Time: 7 15 30\nDistance: 9 40 200\n").to_string(),
+            "lines         2
+line lengths  13 ×1, 18 ×1
+alphabet      \" 01234579:DTaceimnst\" (20 characters)
+grid          no, the lines differ in length
+numbers       6, from 7 to 200
+fits in       i32, u32, i64, u64
+"
+        );
+    }
+}
@@ -0,0 +1,444 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+/// The environment variable holding the adventofcode.com session cookie,
+/// which wins over [`session_file`].
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Who to tell adventofcode.com is asking, as its author requests of tools.
+const USER_AGENT: &str = "github.com/mmhand123/advent-of-code-2023 aoc runner";
+
+/// A private leaderboard as adventofcode.com exports it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leaderboard {
+    pub year: i64,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    /// When each star was got, in Unix seconds, by day and part.
+    pub stars: BTreeMap<(u8, u8), i64>,
+}
+
+/// How points are handed out, always per star or per day and to the
+/// fastest first: with `n` members, first place gets `n` points, second
+/// `n - 1` and so on.
+#[derive(Debug, Clone, Copy, PartialEq, clap::ValueEnum)]
+pub enum Scoring {
+    /// adventofcode.com's local score: points for every star, by when it was got.
+    Local,
+    /// One point per star, however long it took.
+    Stars,
+    /// Points for every day with both stars, by the time between them.
+    Delta,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> io::Result<Leaderboard> {
+        let json: Value = serde_json::from_str(json).map_err(invalid)?;
+        let year = match &json["event"] {
+            Value::String(year) => year.parse().ok(),
+            year => year.as_i64(),
+        }
+        .ok_or_else(|| invalid("the leaderboard has no event year"))?;
+        let members = json["members"]
+            .as_object()
+            .ok_or_else(|| invalid("the leaderboard has no members"))?;
+
+        let mut members: Vec<Member> = members
+            .values()
+            .map(Member::parse)
+            .collect::<io::Result<_>>()?;
+        members.sort_by_key(|member| member.id);
+
+        Ok(Leaderboard { year, members })
+    }
+
+    /// When a day's puzzle opened, at midnight US Eastern time.
+    pub fn unlock(&self, day: u8) -> i64 {
+        let days = days_from_civil(self.year, 12, i64::from(day));
+
+        days * 86_400 + 5 * 3_600
+    }
+
+    /// Points per member under `scoring`, best first, ties going to whoever
+    /// got their last star first.
+    pub fn standings(&self, scoring: Scoring) -> Vec<(&Member, usize)> {
+        let count = self.members.len();
+        let mut points = vec![0; count];
+        let mut award = |times: Vec<(i64, usize)>| {
+            let mut times = times;
+            times.sort();
+
+            for (rank, (_, member)) in times.into_iter().enumerate() {
+                points[member] += count - rank;
+            }
+        };
+
+        for day in 1..=25 {
+            match scoring {
+                Scoring::Local => {
+                    for part in 1..=2 {
+                        award(self.times(|member| member.stars.get(&(day, part)).copied()));
+                    }
+                }
+                Scoring::Stars => {}
+                Scoring::Delta => award(self.times(|member| member.between_stars(day))),
+            }
+        }
+
+        if scoring == Scoring::Stars {
+            for (member, points) in self.members.iter().zip(&mut points) {
+                *points = member.stars.len();
+            }
+        }
+
+        let mut standings: Vec<(&Member, usize)> = self.members.iter().zip(points).collect();
+        standings.sort_by_key(|(member, points)| {
+            (
+                std::cmp::Reverse(*points),
+                member.stars.values().max().copied().unwrap_or(i64::MAX),
+                member.id,
+            )
+        });
+
+        standings
+    }
+
+    /// `time` for every member it has one for, by index into the members.
+    fn times(&self, time: impl Fn(&Member) -> Option<i64>) -> Vec<(i64, usize)> {
+        self.members
+            .iter()
+            .enumerate()
+            .filter_map(|(index, member)| Some((time(member)?, index)))
+            .collect()
+    }
+}
+
+impl Member {
+    fn parse(json: &Value) -> io::Result<Member> {
+        let id = json["id"]
+            .as_u64()
+            .ok_or_else(|| invalid("a member has no id"))?;
+        let name = match json["name"].as_str() {
+            Some(name) => name.to_string(),
+            None => format!("(anonymous user #{id})"),
+        };
+
+        let mut stars = BTreeMap::new();
+        if let Some(days) = json["completion_day_level"].as_object() {
+            for (day, parts) in days {
+                for (part, star) in parts.as_object().into_iter().flatten() {
+                    let (Ok(day), Ok(part), Some(time)) =
+                        (day.parse(), part.parse(), star["get_star_ts"].as_i64())
+                    else {
+                        return Err(invalid(format!("member {id} has a malformed star")));
+                    };
+
+                    stars.insert((day, part), time);
+                }
+            }
+        }
+
+        Ok(Member { id, name, stars })
+    }
+
+    /// How long after part 1 of `day` this member got part 2.
+    pub fn between_stars(&self, day: u8) -> Option<i64> {
+        Some(self.stars.get(&(day, 2))? - self.stars.get(&(day, 1))?)
+    }
+}
+
+/// The standings under `scoring`, then every day anyone has a star for with
+/// each member's time to each star after the puzzle opened and the time
+/// between their stars.
+pub fn render(leaderboard: &Leaderboard, scoring: Scoring) -> String {
+    let mut out = String::new();
+    let name_width = leaderboard
+        .members
+        .iter()
+        .map(|member| member.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    out += &format!(
+        "{} private leaderboard, {} scoring\n\n",
+        leaderboard.year,
+        scoring_name(scoring)
+    );
+
+    for (rank, (member, points)) in leaderboard.standings(scoring).into_iter().enumerate() {
+        out += &format!(
+            "{:>3})  {points:>4}  {:>2} ★  {}\n",
+            rank + 1,
+            member.stars.len(),
+            member.name
+        );
+    }
+
+    let days: Vec<u8> = (1..=25)
+        .filter(|&day| {
+            leaderboard
+                .members
+                .iter()
+                .any(|member| member.stars.contains_key(&(day, 1)))
+        })
+        .collect();
+
+    for day in days {
+        let unlock = leaderboard.unlock(day);
+        let since_unlock = |member: &Member, part| {
+            member
+                .stars
+                .get(&(day, part))
+                .map_or("-".to_string(), |&time| clock(time - unlock))
+        };
+
+        out += &format!(
+            "\n{:<name_width$}  {:<10}  {:<10}  between\n",
+            format!("day {day}"),
+            "part 1",
+            "part 2"
+        );
+
+        for member in &leaderboard.members {
+            if !member.stars.contains_key(&(day, 1)) {
+                continue;
+            }
+
+            let between = member
+                .between_stars(day)
+                .map_or(String::new(), |delta| format!("+{}", clock(delta)));
+            let line = format!(
+                "{:<name_width$}  {:<10}  {:<10}  {between}",
+                member.name,
+                since_unlock(member, 1),
+                since_unlock(member, 2),
+            );
+
+            out += line.trim_end();
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Fetches private leaderboard `id` for `year` from `server`, logged in with
+/// the `session` cookie.
+pub fn fetch(server: &str, year: i64, id: u64, session: &str) -> io::Result<String> {
+    let url = format!(
+        "{}/{year}/leaderboard/private/view/{id}.json",
+        server.trim_end_matches('/')
+    );
+
+    ureq::get(&url)
+        .header("Cookie", format!("session={}", session.trim()))
+        .header("User-Agent", USER_AGENT)
+        .call()
+        .and_then(|mut response| response.body_mut().read_to_string())
+        .map_err(|error| io::Error::other(format!("{url}: {error}")))
+}
+
+/// Where the session cookie is kept when it is not in [`SESSION_VAR`]:
+/// `.aoc/session`, which git ignores.
+pub fn session_file(root: &Path) -> PathBuf {
+    root.join(".aoc").join("session")
+}
+
+/// The session cookie from [`SESSION_VAR`], or else from [`session_file`].
+pub fn session(root: &Path) -> io::Result<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session);
+    }
+
+    fs::read_to_string(session_file(root)).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!(
+                "no session cookie: set {SESSION_VAR} or save it in {}",
+                session_file(root).display()
+            ),
+        )
+    })
+}
+
+fn scoring_name(scoring: Scoring) -> &'static str {
+    match scoring {
+        Scoring::Local => "local",
+        Scoring::Stars => "stars",
+        Scoring::Delta => "delta",
+    }
+}
+
+/// `h:mm:ss`, with as many hours as it takes.
+fn clock(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn invalid(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// Day 1 opened at 1701406800 and day 2 at 1701493200.
+    const EXPORT: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "2": {
+                "id": 2, "name": "Bob", "stars": 3, "local_score": 8,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407000, "star_index": 10},
+                        "2": {"get_star_ts": 1701408000, "star_index": 30}
+                    },
+                    "2": {"1": {"get_star_ts": 1701494100, "star_index": 50}}
+                }
+            },
+            "1": {
+                "id": 1, "name": "Ann", "stars": 4, "local_score": 10,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407100, "star_index": 20},
+                        "2": {"get_star_ts": 1701407400, "star_index": 25}
+                    },
+                    "2": {
+                        "1": {"get_star_ts": 1701494200, "star_index": 60},
+                        "2": {"get_star_ts": 1701498200, "star_index": 70}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": null, "stars": 1, "local_score": 1,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701410800, "star_index": 40}}
+                }
+            }
+        }
+    }"#;
+
+    fn scores(leaderboard: &Leaderboard, scoring: Scoring) -> Vec<(u64, usize)> {
+        leaderboard
+            .standings(scoring)
+            .into_iter()
+            .map(|(member, points)| (member.id, points))
+            .collect()
+    }
+
+    #[test]
+    fn recomputes_the_local_score() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+
+        assert_eq!(leaderboard.unlock(1), 1701406800);
+        assert_eq!(leaderboard.unlock(2), 1701493200);
+        assert_eq!(
+            scores(&leaderboard, Scoring::Local),
+            vec![(1, 10), (2, 8), (3, 1)]
+        );
+        assert_eq!(
+            scores(&leaderboard, Scoring::Stars),
+            vec![(1, 4), (2, 3), (3, 1)]
+        );
+        assert_eq!(
+            scores(&leaderboard, Scoring::Delta),
+            vec![(1, 6), (2, 2), (3, 0)]
+        );
+    }
+
+    #[test]
+    fn renders_standings_and_star_times() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+
+        assert_eq!(
+            render(&leaderboard, Scoring::Local),
+            "2023 private leaderboard, local scoring
+
+  1)    10   4 ★  Ann
+  2)     8   3 ★  Bob
+  3)     1   1 ★  (anonymous user #3)
+
+day 1                part 1      part 2      between
+Ann                  0:05:00     0:10:00     +0:05:00
+Bob                  0:03:20     0:20:00     +0:16:40
+(anonymous user #3)  1:06:40     -
+
+day 2                part 1      part 2      between
+Ann                  0:16:40     1:23:20     +1:06:40
+Bob                  0:15:00     -
+"
+        );
+    }
+
+    #[test]
+    fn refuses_malformed_exports() {
+        assert!(Leaderboard::parse("[]").is_err());
+        assert!(Leaderboard::parse(r#"{"event": "2023"}"#).is_err());
+        assert!(Leaderboard::parse(
+            r#"{"event": "2023", "members": {"1": {"id": 1, "completion_day_level": {"1": {"1": {}}}}}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn fetches_with_the_session_cookie() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", server.server_addr());
+
+        let mock = thread::spawn(move || {
+            for status in [200, 500] {
+                let request = server.recv().unwrap();
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string());
+
+                assert_eq!(request.url(), "/2023/leaderboard/private/view/1.json");
+                assert_eq!(cookie.as_deref(), Some("session=53cr3t"));
+
+                let response = Response::from_string(EXPORT).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+
+        let json = fetch(&address, 2023, 1, "53cr3t\n").unwrap();
+        assert_eq!(Leaderboard::parse(&json).unwrap().members.len(), 3);
+        assert!(fetch(&address, 2023, 1, "53cr3t").is_err());
+
+        mock.join().unwrap();
+    }
+}
@@ -0,0 +1,479 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use clap::{Parser, Subcommand};
+use common::secret::Key;
+
+mod cache;
+mod cargo;
+mod day;
+mod hash;
+mod history;
+mod inputs;
+mod inspect;
+mod leaderboard;
+mod profile;
+mod progress;
+mod puzzle;
+mod run;
+mod serve;
+// built into the build script, and here only to test what it embedded
+mod sources;
+mod tui;
+mod watch;
+
+use day::Day;
+use profile::Profile;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runner for the advent of code 2023 solutions")]
+struct Cli {
+    /// Directory containing the `day-NN` crates
+    #[arg(long, global = true, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+
+    /// Show the solvers' logs: `-v` for results, `-vv` for details, `-vvv` for everything
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Re-run a day's example tests and solvers whenever its files change
+    Watch {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+    },
+    /// Print a random input for a day, the same one every time for a given seed
+    Gen {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many lines, races, nodes or grid rows the input has
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+    /// Feed a part's solver arbitrary input until it panics or time runs out
+    Fuzz {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+        /// Which part to fuzz
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Stop after this many seconds instead of running until interrupted
+        #[arg(long)]
+        seconds: Option<u64>,
+    },
+    /// Solve days against their real inputs, several parts at a time
+    Run {
+        /// Day numbers, e.g. `7` for `day-07`
+        #[arg(required_unless_present_any = ["all", "all_profiles"], conflicts_with = "all")]
+        days: Vec<u8>,
+        /// Run every day
+        #[arg(long)]
+        all: bool,
+        /// Solve a team member's inputs from `inputs/<PROFILE>/day-NN.txt` instead of the bundled ones
+        #[arg(long, conflicts_with = "all_profiles")]
+        profile: Option<String>,
+        /// Solve the bundled inputs and every profile's, every day unless days are given, and tabulate the answers
+        #[arg(long)]
+        all_profiles: bool,
+        /// Give up on a part after this many seconds
+        #[arg(long, default_value_t = 30)]
+        timeout: u64,
+        /// How many parts run at once, by default one per CPU
+        #[arg(long)]
+        jobs: Option<usize>,
+        /// Only print the answers, without the progress of the parts still running
+        #[arg(short, long)]
+        quiet: bool,
+        /// Solve every part again rather than reuse answers cached for the same input and sources
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Summarise an input's lines, characters and numbers, to choose parsers and integer types by
+    Inspect {
+        /// The input file, which may be stored sealed
+        file: PathBuf,
+    },
+    /// Check an input against the assumptions a day's solvers make, listing every line that breaks one
+    Validate {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+        /// The input to check instead of the day's own, which may be stored sealed
+        file: Option<PathBuf>,
+    },
+    /// Show a private leaderboard's standings, star times and scores under other rules
+    Leaderboard {
+        /// The leaderboard exported as JSON from adventofcode.com
+        #[arg(required_unless_present = "fetch", conflicts_with = "fetch")]
+        file: Option<PathBuf>,
+        /// Download the leaderboard with this id instead, with the session cookie in `AOC_SESSION` or `.aoc/session`
+        #[arg(long, value_name = "ID")]
+        fetch: Option<u64>,
+        /// The event to download the leaderboard of
+        #[arg(long, default_value_t = 2023)]
+        year: i64,
+        /// How to award points in the standings
+        #[arg(long, value_enum, default_value_t = leaderboard::Scoring::Local)]
+        scoring: leaderboard::Scoring,
+        /// Where to download from
+        #[arg(long, default_value = "https://adventofcode.com")]
+        server: String,
+    },
+    /// Print a day's puzzle description, or save it from a puzzle page
+    Puzzle {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+        /// Convert this puzzle page, saved from adventofcode.com with both parts, to the day's `PUZZLE.md` instead
+        #[arg(long, value_name = "HTML")]
+        import: Option<PathBuf>,
+    },
+    /// Show how a day's answers and runtimes changed from run to run, marking the answers taken
+    /// from the cache
+    History {
+        /// Day number, e.g. `7` for `day-07`
+        day: u8,
+    },
+    /// Seal, unseal or check the puzzle inputs, which are committed encrypted
+    Inputs {
+        #[command(subcommand)]
+        action: InputsAction,
+    },
+    /// Browse every day's tests, answers and timings, and run days from the list
+    Tui,
+    /// Solve inputs posted to `POST /day/{d}/part/{p}` on localhost, answering in JSON
+    Serve {
+        #[arg(long, default_value_t = 8023)]
+        port: u16,
+        /// Largest input accepted, in bytes
+        #[arg(long, default_value_t = 1 << 20)]
+        max_input: usize,
+        /// Give up on a solver after this many seconds
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+        /// How many solvers run at once, by default one per CPU
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+enum InputsAction {
+    /// Seal every plain text input and delete it, making a key in `.aoc/input.key` if there is none
+    Encrypt,
+    /// Write a plain text copy of every sealed input next to it, where git ignores it
+    Decrypt,
+    /// Fail if git tracks any input in plain text
+    Check,
+}
+
+impl InputsAction {
+    fn verb(self) -> &'static str {
+        match self {
+            InputsAction::Encrypt => "encrypt the inputs",
+            InputsAction::Decrypt => "decrypt the inputs",
+            InputsAction::Check => "check the inputs",
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Watch { day } => {
+            let Some(day) = find_day(&cli.root, day) else {
+                return ExitCode::FAILURE;
+            };
+
+            if let Err(error) = watch::watch(&day, cli.verbose) {
+                eprintln!("watching {} failed: {error}", day.name());
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let Some(input) = common::generate::input(day, seed, size) else {
+                eprintln!("no input generator for day {day}");
+                return ExitCode::FAILURE;
+            };
+
+            print!("{input}");
+        }
+        Command::Fuzz { day, part, seconds } => {
+            let Some(day) = find_day(&cli.root, day) else {
+                return ExitCode::FAILURE;
+            };
+
+            match cargo::fuzz(&day, part, seconds) {
+                Ok(status) if status.success() => {}
+                Ok(_) => return ExitCode::FAILURE,
+                Err(error) => {
+                    eprintln!("could not start cargo-fuzz for {}: {error}", day.name());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Run {
+            days,
+            all,
+            profile,
+            all_profiles,
+            timeout,
+            jobs,
+            quiet,
+            no_cache,
+        } => {
+            let days = match all || days.is_empty() {
+                true => Day::all(&cli.root),
+                false => match days.iter().map(|&day| find_day(&cli.root, day)).collect() {
+                    Some(days) => days,
+                    None => return ExitCode::FAILURE,
+                },
+            };
+            let jobs = jobs
+                .unwrap_or_else(|| thread::available_parallelism().map_or(1, |cpus| cpus.get()));
+
+            let profiles = match (all_profiles, profile) {
+                (true, _) => match Profile::all(&cli.root) {
+                    Ok(profiles) => profiles,
+                    Err(error) => {
+                        eprintln!("could not list the profiles in inputs/: {error}");
+                        return ExitCode::FAILURE;
+                    }
+                },
+                (false, Some(name)) => vec![Profile::named(&cli.root, &name)],
+                (false, None) => vec![Profile::Bundled],
+            };
+
+            init_logging(cli.verbose);
+
+            // the status line would be redrawn over the solvers' logs
+            if !run::run(
+                &cli.root,
+                &days,
+                &profiles,
+                jobs,
+                Duration::from_secs(timeout),
+                progress::Mode::detect(quiet || cli.verbose > 0),
+                no_cache,
+            ) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Inspect { file } => match common::secret::read(&file) {
+            Ok(input) => print!("{}", inspect::inspect(&input)),
+            Err(error) => {
+                eprintln!("could not read {}: {error}", file.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Validate { day, file } => return validate(&cli.root, day, file),
+        Command::Leaderboard {
+            file,
+            fetch,
+            year,
+            scoring,
+            server,
+        } => {
+            let json = match (file, fetch) {
+                (Some(file), _) => std::fs::read_to_string(&file)
+                    .map_err(|error| format!("could not read {}: {error}", file.display())),
+                (None, Some(id)) => leaderboard::session(&cli.root)
+                    .and_then(|session| leaderboard::fetch(&server, year, id, &session))
+                    .map_err(|error| format!("could not download leaderboard {id}: {error}")),
+                (None, None) => unreachable!("clap requires a file or --fetch"),
+            };
+
+            match json.and_then(|json| {
+                leaderboard::Leaderboard::parse(&json)
+                    .map_err(|error| format!("could not read the leaderboard: {error}"))
+            }) {
+                Ok(board) => print!("{}", leaderboard::render(&board, scoring)),
+                Err(error) => {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Puzzle { day, import } => {
+            let Some(day) = find_day(&cli.root, day) else {
+                return ExitCode::FAILURE;
+            };
+
+            match import {
+                Some(html) => match puzzle::import(&day, &html) {
+                    Ok(()) => println!("wrote {}", day.puzzle().display()),
+                    Err(error) => {
+                        eprintln!("could not convert {}: {error}", html.display());
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => match std::fs::read_to_string(day.puzzle()) {
+                    Ok(markdown) => print!("{markdown}"),
+                    Err(error) => {
+                        eprintln!(
+                            "no puzzle description for {} ({error}), save its page and run `aoc puzzle {} --import <page.html>`",
+                            day.name(),
+                            day.number
+                        );
+                        return ExitCode::FAILURE;
+                    }
+                },
+            }
+        }
+        Command::History { day } => {
+            let records = history::History::open(&cli.root).and_then(|history| history.day(day));
+
+            match records {
+                Ok(records) if records.is_empty() => println!("no runs of day {day} recorded yet"),
+                Ok(records) => history::print(&records),
+                Err(error) => {
+                    eprintln!("could not read the run history: {error}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Inputs { action } => {
+            let files = match inputs::files(&cli.root) {
+                Ok(files) => files,
+                Err(error) => {
+                    eprintln!("could not list the inputs: {error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let done = match action {
+                InputsAction::Encrypt => inputs::key_or_new()
+                    .and_then(|key| inputs::encrypt(&files, &key))
+                    .map(|()| true),
+                InputsAction::Decrypt => Key::load()
+                    .and_then(|key| inputs::decrypt(&files, &key))
+                    .map(|()| true),
+                InputsAction::Check => inputs::check(&cli.root, &files).map(|refused| {
+                    for file in &refused {
+                        eprintln!("{file}");
+                    }
+
+                    if !refused.is_empty() {
+                        eprintln!(
+                            "run `aoc inputs encrypt`, `git rm --cached` the plain text and commit the sealed inputs"
+                        );
+                    }
+
+                    refused.is_empty()
+                }),
+            };
+
+            match done {
+                Ok(true) => {}
+                Ok(false) => return ExitCode::FAILURE,
+                Err(error) => {
+                    eprintln!("could not {}: {error}", action.verb());
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Tui => {
+            if let Err(error) = tui::tui(&cli.root, cli.verbose) {
+                eprintln!("the dashboard failed: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Serve {
+            port,
+            max_input,
+            timeout,
+            jobs,
+        } => {
+            init_logging(cli.verbose);
+
+            let limits = serve::Limits {
+                max_input,
+                timeout: Duration::from_secs(timeout),
+                jobs: jobs.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, |cpus| cpus.get())
+                }),
+            };
+
+            if let Err(error) = serve::serve(port, limits) {
+                eprintln!("could not serve on port {port}: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Prints the assumptions each part's input breaks, failing if any does.
+fn validate(root: &Path, number: u8, file: Option<PathBuf>) -> ExitCode {
+    let Some(day) = find_day(root, number) else {
+        return ExitCode::FAILURE;
+    };
+    let mut broken = false;
+
+    for part in day.parts() {
+        let Some(path) = file.clone().or_else(|| day.input(part)) else {
+            eprintln!("part {part}: no input to check");
+            broken = true;
+            continue;
+        };
+
+        let report = common::secret::read(&path)
+            .map_err(|error| format!("could not read {}: {error}", path.display()))
+            .and_then(|input| {
+                solvers::validate(number, part, &input).map_err(|error| error.to_string())
+            });
+
+        match report {
+            Ok(report) if report.is_empty() => println!("part {part}: no broken assumptions"),
+            Ok(report) => {
+                println!("part {part}:");
+                for line in report.to_string().lines() {
+                    println!("  {line}");
+                }
+                broken = true;
+            }
+            Err(error) => {
+                eprintln!("part {part}: {error}");
+                broken = true;
+            }
+        }
+    }
+
+    match broken {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+/// Turns on the logging of the solvers `aoc` runs in-process, at the level the
+/// `-v` flags ask for, or as `AOC_LOG` says without them.
+fn init_logging(verbosity: u8) {
+    let directives = cargo::log_level(verbosity)
+        .map(str::to_string)
+        .or_else(|| std::env::var(common::logging::ENV_VAR).ok());
+
+    if let Some(directives) = directives {
+        common::logging::init_all(&directives);
+    }
+}
+
+fn find_day(root: &Path, number: u8) -> Option<Day> {
+    let day = Day::find(root, number);
+
+    if day.is_none() {
+        eprintln!("no crate for day {number} in {}", root.display());
+    }
+
+    day
+}
@@ -0,0 +1,93 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::secret;
+
+use crate::day::Day;
+
+/// Whose puzzle inputs to solve: the ones bundled with each day's binaries,
+/// or a team member's from `inputs/<profile>/day-NN.txt`, one file per day
+/// since both parts share an input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Profile {
+    Bundled,
+    Named { name: String, dir: PathBuf },
+}
+
+impl Profile {
+    pub fn named(root: &Path, name: &str) -> Profile {
+        Profile::Named {
+            name: name.to_string(),
+            dir: root.join("inputs").join(name),
+        }
+    }
+
+    /// The bundled inputs, then every directory under `inputs/` by name.
+    pub fn all(root: &Path) -> io::Result<Vec<Profile>> {
+        let mut names = match fs::read_dir(root.join("inputs")) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error),
+        };
+        names.sort();
+
+        let named = names.iter().map(|name| Profile::named(root, name));
+
+        Ok([Profile::Bundled].into_iter().chain(named).collect())
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Profile::Bundled => "bundled",
+            Profile::Named { name, .. } => name,
+        }
+    }
+
+    /// Where this profile keeps its input for a part, whether or not it has one.
+    pub fn path(&self, day: &Day, part: u8) -> Option<PathBuf> {
+        match self {
+            Profile::Bundled => day.input(part),
+            Profile::Named { dir, .. } => Some(dir.join(format!("{}.txt", day.name()))),
+        }
+    }
+
+    /// The input this profile has for a part, if any, plain or sealed.
+    pub fn input(&self, day: &Day, part: u8) -> Option<PathBuf> {
+        self.path(day, part).filter(|path| secret::exists(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_profile_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let day = Day::find(&repo, 6).unwrap();
+
+        fs::create_dir_all(root.join("inputs/zoe")).unwrap();
+        fs::create_dir_all(root.join("inputs/ann")).unwrap();
+        fs::write(root.join("inputs/ann/day-06.txt"), "Time: 7\nDistance: 9\n").unwrap();
+
+        let profiles = Profile::all(&root).unwrap();
+        let names: Vec<&str> = profiles.iter().map(Profile::name).collect();
+        assert_eq!(names, vec!["bundled", "ann", "zoe"]);
+
+        assert!(profiles[0].input(&day, 1).unwrap().ends_with("input1.txt"));
+        assert!(profiles[1]
+            .input(&day, 2)
+            .unwrap()
+            .ends_with("ann/day-06.txt"));
+        assert_eq!(profiles[2].input(&day, 1), None);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
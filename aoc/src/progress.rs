@@ -0,0 +1,99 @@
+use std::io::{self, IsTerminal, Write};
+
+use common::context::Context;
+
+/// How `aoc run` shows the parts it is solving while they run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Nothing but the reports, e.g. with `--quiet` or when piped.
+    Hidden,
+    /// A status line on stderr, redrawn as the solvers report.
+    Line,
+}
+
+impl Mode {
+    /// [`Mode::Line`] on a terminal unless `quiet`, so that progress never
+    /// ends up in a file or another program's input.
+    pub fn detect(quiet: bool) -> Mode {
+        match !quiet && io::stderr().is_terminal() {
+            true => Mode::Line,
+            false => Mode::Hidden,
+        }
+    }
+}
+
+/// A part being solved, with the context its solver reports into.
+#[derive(Debug, Clone)]
+pub struct Running {
+    pub day: u8,
+    pub part: u8,
+    pub context: Context,
+}
+
+impl Running {
+    /// `day-05 part2 43%`, or without the percentage while the solver has
+    /// reported nothing, as most never do.
+    pub fn summary(&self) -> String {
+        let label = format!("day-{:02} part{}", self.day, self.part);
+
+        match self.context.progress().ratio() {
+            Some(ratio) => format!("{label} {:.0}%", ratio * 100.0),
+            None => label,
+        }
+    }
+}
+
+/// The status line of [`Mode::Line`], which has to be cleared before
+/// anything else is printed.
+#[derive(Debug)]
+pub struct StatusLine {
+    mode: Mode,
+    drawn: bool,
+}
+
+impl StatusLine {
+    pub fn new(mode: Mode) -> StatusLine {
+        StatusLine { mode, drawn: false }
+    }
+
+    pub fn draw(&mut self, running: &[Running]) {
+        if self.mode == Mode::Hidden || running.is_empty() {
+            return self.clear();
+        }
+
+        let summaries: Vec<String> = running.iter().map(Running::summary).collect();
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2Ksolving {}", summaries.join(", "));
+        let _ = stderr.flush();
+        self.drawn = true;
+    }
+
+    pub fn clear(&mut self) {
+        if self.drawn {
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+            self.drawn = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarises_reported_progress() {
+        let running = Running {
+            day: 8,
+            part: 2,
+            context: Context::new(),
+        };
+
+        assert_eq!(running.summary(), "day-08 part2");
+
+        running.context.progress().add_total(6);
+        running.context.progress().advance(2);
+        assert_eq!(running.summary(), "day-08 part2 33%");
+    }
+}
@@ -0,0 +1,403 @@
+use std::{fmt, fs, io, path::Path};
+
+use crate::day::Day;
+
+/// Why a saved page could not be converted.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The page has no `<article class="day-desc">`, so is not a puzzle page,
+    /// or was saved before the puzzle opened.
+    NoPuzzle,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoPuzzle => write!(f, "the page has no puzzle description in it"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Converts `html` to a `PUZZLE.md` for `day`, keeping the puzzle's text
+/// and nothing else of the page.
+pub fn import(day: &Day, html: &Path) -> io::Result<()> {
+    let html = fs::read_to_string(html)?;
+    let markdown =
+        to_markdown(&html).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    fs::write(day.puzzle(), markdown)
+}
+
+/// The puzzle description in a page saved from adventofcode.com, as
+/// Markdown: the title, both parts if the page has them, and their examples
+/// as code blocks with emphasis kept. The answers and the rest of the page
+/// are left out.
+pub fn to_markdown(html: &str) -> Result<String, Error> {
+    let mut markdown = Markdown::default();
+    let mut depth = 0;
+
+    for token in tokens(html) {
+        match token {
+            Token::Start(tag, attributes)
+                if tag == "article" && (depth > 0 || attributes.contains("day-desc")) =>
+            {
+                depth += 1;
+            }
+            Token::End(tag) if tag == "article" && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    markdown.articles += 1;
+                }
+            }
+            token if depth > 0 => markdown.push(token),
+            _ => {}
+        }
+    }
+
+    if markdown.articles == 0 {
+        return Err(Error::NoPuzzle);
+    }
+
+    Ok(markdown.finish())
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// A start tag's lowercase name and the rest of it, attributes and all.
+    Start(String, &'a str),
+    End(String),
+    Text(&'a str),
+}
+
+/// The tags and text of `html`, without comments. Good enough for the
+/// regular pages of one site, not for HTML in general.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.split_once("-->").map_or("", |(_, after)| after);
+            continue;
+        }
+
+        let Some(tag) = rest.strip_prefix('<') else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        };
+
+        let Some((tag, after)) = tag.split_once('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        rest = after;
+
+        let tag = tag.trim_end_matches('/');
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+
+        match name.strip_prefix('/') {
+            Some(name) => tokens.push(Token::End(name.to_ascii_lowercase())),
+            None => tokens.push(Token::Start(name.to_ascii_lowercase(), attributes)),
+        }
+    }
+
+    tokens
+}
+
+/// The Markdown written so far, and what the converter is inside of.
+#[derive(Debug, Default)]
+struct Markdown {
+    out: String,
+    articles: usize,
+    heading: Option<String>,
+    pre: bool,
+    /// Inline code being collected, and whether it is emphasised, since
+    /// Markdown cannot emphasise part of a code span.
+    code: Option<(String, bool)>,
+    links: Vec<String>,
+}
+
+impl Markdown {
+    fn push(&mut self, token: Token<'_>) {
+        match token {
+            Token::Start(tag, attributes) => self.start(&tag, attributes),
+            Token::End(tag) => self.end(&tag),
+            Token::Text(text) => self.text(&decode(text)),
+        }
+    }
+
+    fn start(&mut self, tag: &str, attributes: &str) {
+        match tag {
+            "h2" => self.heading = Some(String::new()),
+            "pre" => {
+                self.block();
+                self.out += "```\n";
+                self.pre = true;
+            }
+            "code" if !self.pre => self.code = Some((String::new(), false)),
+            "em" if self.pre => {}
+            "em" => match &mut self.code {
+                Some((_, emphasised)) => *emphasised = true,
+                None => self.out.push('*'),
+            },
+            "a" => {
+                self.links
+                    .push(attribute(attributes, "href").unwrap_or_default());
+                self.out.push('[');
+            }
+            "li" => {
+                self.line();
+                self.out += "- ";
+            }
+            "p" | "ul" => self.block(),
+            "br" => self.out.push('\n'),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: &str) {
+        match tag {
+            "h2" => {
+                let heading = self.heading.take().unwrap_or_default();
+                let heading = heading.trim().trim_matches('-').trim();
+                let level = if self.articles == 0 { "#" } else { "##" };
+
+                self.block();
+                self.out += &format!("{level} {heading}\n\n");
+            }
+            "pre" => {
+                self.line();
+                self.out += "```\n\n";
+                self.pre = false;
+            }
+            "code" if !self.pre => {
+                if let Some((code, emphasised)) = self.code.take() {
+                    let fence = if code.contains('`') { "``" } else { "`" };
+                    let code = format!("{fence}{code}{fence}");
+
+                    match emphasised {
+                        true => self.out += &format!("*{code}*"),
+                        false => self.out += &code,
+                    }
+                }
+            }
+            "em" if !self.pre && self.code.is_none() => self.out.push('*'),
+            "a" => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out += &format!("]({href})");
+            }
+            "p" | "ul" => self.out += "\n\n",
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some(heading) = &mut self.heading {
+            heading.push_str(text);
+        } else if self.pre {
+            self.out += text;
+        } else if let Some((code, _)) = &mut self.code {
+            code.push_str(&collapse(text));
+        } else {
+            let text = collapse(text);
+            let text = match self.out.ends_with(['\n', ' ']) || self.out.is_empty() {
+                true => text.trim_start(),
+                false => &text,
+            };
+
+            for char in text.chars() {
+                if matches!(char, '\\' | '*' | '_' | '`' | '[' | ']') {
+                    self.out.push('\\');
+                }
+                self.out.push(char);
+            }
+        }
+    }
+
+    /// Starts a new line unless at the start of one.
+    fn line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// Leaves a blank line before a block, unless at the start.
+    fn block(&mut self) {
+        self.line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    /// The Markdown with single blank lines between blocks and no spaces at
+    /// the ends of lines.
+    fn finish(self) -> String {
+        let mut markdown = String::new();
+        let mut blank = true;
+
+        for line in self.out.lines().map(str::trim_end) {
+            if line.is_empty() {
+                if !blank {
+                    markdown.push('\n');
+                }
+                blank = true;
+                continue;
+            }
+
+            markdown += line;
+            markdown.push('\n');
+            blank = false;
+        }
+
+        markdown.trim_end().to_string() + "\n"
+    }
+}
+
+/// Runs of whitespace as single spaces, as a browser shows them.
+fn collapse(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut space = false;
+
+    for char in text.chars() {
+        if char.is_whitespace() {
+            if !space {
+                collapsed.push(' ');
+            }
+            space = true;
+        } else {
+            collapsed.push(char);
+            space = false;
+        }
+    }
+
+    collapsed
+}
+
+/// The value of a double-quoted attribute.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let (_, rest) = attributes.split_once(&format!("{name}=\""))?;
+    let (value, _) = rest.split_once('"')?;
+
+    Some(decode(value))
+}
+
+/// `text` with its character references replaced by the characters.
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let reference = rest[1..].split_once(';').and_then(|(name, _)| {
+            let char = match name {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => '\u{a0}',
+                _ => {
+                    let code = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => name.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+
+            Some((char, name.len() + 2))
+        });
+
+        match reference {
+            Some((char, length)) => {
+                decoded.push(char);
+                rest = &rest[length..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded + rest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><head><title>Day 7 - Advent of Code 2023</title></head>
+<body><main>
+<article class="day-desc"><h2>--- Day 7: Camel Cards ---</h2><p>Your all-expenses-paid trip turns out to be a one-way, five-minute ride in an <a href="https://en.wikipedia.org/wiki/Airship" target="_blank">airship</a>.</p>
+<p>In Camel Cards, you get a list of <em>hands</em>, and your goal is to order them based on the <em>strength</em> of each hand:</p>
+<ul>
+<li><em>Five of a kind</em>, where all five cards have the same label: <code>AAAAA</code></li>
+<li><em>High card</em>, where all cards' labels are distinct: <code>23456</code></li>
+</ul>
+<pre><code>32T3K 765
+T55J5 684
+</code></pre>
+<p>So, the total winnings in this example are <code><em>6440</em></code>.</p>
+<!-- a comment, <em>not</em> text -->
+</article>
+<p>Your puzzle answer was <code>250474325</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now, <code>J</code> cards are jokers &amp; count as <span title="wildcards">whatever</span> card would make the hand the strongest type; <code>J</code> &lt; <code>2</code>.</p>
+<p>With the new joker rule, the total winnings are <code><em>5905</em></code>.</p>
+</article>
+<p>Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main></body></html>"#;
+
+    #[test]
+    fn converts_both_parts() {
+        assert_eq!(
+            to_markdown(PAGE).unwrap(),
+            "# Day 7: Camel Cards
+
+Your all-expenses-paid trip turns out to be a one-way, five-minute ride in an [airship](https://en.wikipedia.org/wiki/Airship).
+
+In Camel Cards, you get a list of *hands*, and your goal is to order them based on the *strength* of each hand:
+
+- *Five of a kind*, where all five cards have the same label: `AAAAA`
+- *High card*, where all cards' labels are distinct: `23456`
+
+```
+32T3K 765
+T55J5 684
+```
+
+So, the total winnings in this example are *`6440`*.
+
+## Part Two
+
+Now, `J` cards are jokers & count as whatever card would make the hand the strongest type; `J` < `2`.
+
+With the new joker rule, the total winnings are *`5905`*.
+"
+        );
+    }
+
+    #[test]
+    fn needs_a_puzzle() {
+        assert_eq!(
+            to_markdown("<html><p>404 Not Found</p></html>"),
+            Err(Error::NoPuzzle)
+        );
+    }
+
+    #[test]
+    fn decodes_character_references() {
+        assert_eq!(
+            decode("a &lt;b&gt; &#65;&#x42; & c &bogus;"),
+            "a <b> AB & c &bogus;"
+        );
+        assert_eq!(collapse("a \n  b"), "a b");
+    }
+}
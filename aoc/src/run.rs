@@ -0,0 +1,632 @@
+use std::{
+    fmt, io,
+    path::Path,
+    sync::{mpsc, Arc, Mutex},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use common::{context::Context, secret};
+
+use crate::{
+    cache::{self, Cache, Hit, Key},
+    day::Day,
+    hash,
+    history::{self, Entry, History},
+    profile::Profile,
+    progress::{Mode, Running, StatusLine},
+};
+
+/// How often the progress of running parts is passed on.
+const TICK: Duration = Duration::from_millis(100);
+
+/// How one part's run ended.
+#[derive(Debug)]
+pub enum Outcome {
+    Answered(String),
+    Failed(String),
+    /// The solver was still going when its time ran out.
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    /// The SHA-256 of the input, once it has been read.
+    pub input_hash: Option<String>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Whether the answer came from the [`Cache`], with the time it took
+    /// when it was found.
+    pub cached: bool,
+}
+
+/// A part's input, read and hashed once for both the cache and the solver.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub text: Arc<str>,
+    /// The SHA-256 of the text.
+    pub hash: String,
+}
+
+impl Input {
+    /// Reads the input at `path`, unsealing it if need be, see
+    /// [`common::secret::read`].
+    pub fn read(path: &Path) -> io::Result<Input> {
+        let text = secret::read(path)?;
+        let hash = hash::sha256(text.as_bytes());
+
+        Ok(Input {
+            text: text.into(),
+            hash,
+        })
+    }
+}
+
+/// What [`run_all`] passes on while it runs.
+#[derive(Debug)]
+pub enum Event<'a> {
+    Finished(&'a PartReport),
+    /// The parts still being solved, every [`TICK`] while there are any.
+    Progress(&'a [Running]),
+}
+
+impl Outcome {
+    /// How the run history names the outcome.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Answered(_) => "answered",
+            Outcome::Failed(_) => "failed",
+            Outcome::TimedOut => "timed out",
+        }
+    }
+}
+
+impl fmt::Display for PartReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day-{:02} part{}  ", self.day, self.part)?;
+
+        match &self.outcome {
+            Outcome::Answered(answer) if self.cached => {
+                write!(f, "{answer}  ({:.2?}, cached)", self.elapsed)
+            }
+            Outcome::Answered(answer) => write!(f, "{answer}  ({:.2?})", self.elapsed),
+            Outcome::Failed(error) => write!(f, "FAILED  {error}"),
+            Outcome::TimedOut => write!(f, "TIMED OUT after {:.2?}", self.elapsed),
+        }
+    }
+}
+
+/// Runs `days` for every profile, records the runs in the history and prints
+/// them: as they finish for a single profile, or as a table of answers per
+/// profile for several. The parts still running are shown as `mode` says.
+/// Answers are taken from the cache when it has them, unless `no_cache`.
+/// Tells whether every part answered.
+pub fn run(
+    root: &Path,
+    days: &[Day],
+    profiles: &[Profile],
+    jobs: usize,
+    timeout: Duration,
+    mode: Mode,
+    no_cache: bool,
+) -> bool {
+    let tabulate = profiles.len() > 1;
+    let cache = Cache::open(root, !no_cache)
+        .inspect_err(|error| eprintln!("could not open the answer cache: {error}"))
+        .ok();
+    let mut status = StatusLine::new(mode);
+    let commit = history::git_commit(root);
+    let mut runs = Vec::new();
+
+    for profile in profiles {
+        if !tabulate {
+            for day in days {
+                for part in day.parts() {
+                    if profile.input(day, part).is_none() {
+                        eprintln!(
+                            "{} has no input for {} part{part}",
+                            profile.name(),
+                            day.name()
+                        );
+                    }
+                }
+            }
+        }
+
+        let reports = run_all(
+            days,
+            profile,
+            jobs,
+            timeout,
+            cache.as_ref(),
+            |event| match event {
+                Event::Progress(running) => status.draw(running),
+                Event::Finished(report) => {
+                    status.clear();
+                    if !tabulate {
+                        println!("{report}");
+                    }
+                }
+            },
+        );
+        status.clear();
+
+        History::record_all(
+            root,
+            reports
+                .iter()
+                .filter_map(|report| Entry::from_report(report, &commit)),
+        );
+        runs.push((profile.name().to_string(), reports));
+    }
+
+    if tabulate {
+        print!("{}", table(days, &runs));
+    }
+
+    let reports: Vec<&PartReport> = runs.iter().flat_map(|(_, reports)| reports).collect();
+    let count = |matches: fn(&Outcome) -> bool| {
+        reports
+            .iter()
+            .filter(|report| matches(&report.outcome))
+            .count()
+    };
+    let failed = count(|outcome| matches!(outcome, Outcome::Failed(_)));
+    let timed_out = count(|outcome| matches!(outcome, Outcome::TimedOut));
+
+    println!(
+        "\n{} answered, {failed} failed, {timed_out} timed out",
+        reports.len() - failed - timed_out
+    );
+
+    failed + timed_out == 0
+}
+
+/// One row per part and one column per profile, with `-` where a profile has
+/// no input for the part.
+fn table(days: &[Day], runs: &[(String, Vec<PartReport>)]) -> String {
+    let mut rows = vec![[String::new()]
+        .into_iter()
+        .chain(runs.iter().map(|(profile, _)| profile.clone()))
+        .collect::<Vec<_>>()];
+
+    for day in days {
+        for part in day.parts() {
+            let cells = runs.iter().map(|(_, reports)| {
+                let report = reports
+                    .iter()
+                    .find(|report| report.day == day.number && report.part == part);
+
+                match report.map(|report| &report.outcome) {
+                    Some(Outcome::Answered(answer)) => answer.clone(),
+                    Some(Outcome::Failed(_)) => "FAILED".to_string(),
+                    Some(Outcome::TimedOut) => "TIMED OUT".to_string(),
+                    None => "-".to_string(),
+                }
+            });
+
+            rows.push(
+                [format!("{} part{part}", day.name())]
+                    .into_iter()
+                    .chain(cells)
+                    .collect(),
+            );
+        }
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+
+            format!("{}\n", cells.join("  ").trim_end())
+        })
+        .collect()
+}
+
+/// Solves every part of `days` that `profile` has an input for, in-process on
+/// `jobs` threads, giving each part `timeout` to answer. Reports are sent to
+/// `on_event` as parts finish, along with the progress of the parts still
+/// running, and returned in the order of `days`.
+///
+/// Parts the `cache` has an answer for are reported straight away instead,
+/// and new answers are added to it. So are inputs that cannot be read, which
+/// are read once for both.
+///
+/// Each part gets its own [`Context`], cancelled if it runs out of time. Solvers
+/// that check it stop soon after; the others keep their thread busy until they
+/// finish, and the thread that ran them waits for that before solving another
+/// part, so no more than `jobs` solvers ever run at once. Solvers still going
+/// once every part has been started are left behind rather than waited for.
+pub fn run_all(
+    days: &[Day],
+    profile: &Profile,
+    jobs: usize,
+    timeout: Duration,
+    cache: Option<&Cache>,
+    mut on_event: impl FnMut(Event<'_>),
+) -> Vec<PartReport> {
+    let tasks: Vec<(Day, u8, io::Result<Input>)> = days
+        .iter()
+        .flat_map(|day| {
+            day.parts().into_iter().filter_map(|part| {
+                let input = Input::read(&profile.input(day, part)?);
+
+                Some((day.clone(), part, input))
+            })
+        })
+        .collect();
+    let keys: Vec<Option<Key>> = tasks
+        .iter()
+        .map(|(day, part, input)| cache_key(day, *part, input.as_ref().ok()?))
+        .collect();
+    let mut reports: Vec<Option<PartReport>> = tasks.iter().map(|_| None).collect();
+    let mut uncached = Vec::new();
+
+    for (index, (day, part, input)) in tasks.into_iter().enumerate() {
+        let report = match input {
+            Err(error) => PartReport {
+                day: day.number,
+                part,
+                input_hash: None,
+                outcome: Outcome::Failed(error.to_string()),
+                elapsed: Duration::ZERO,
+                cached: false,
+            },
+            Ok(input) => match cache.zip(keys[index].as_ref()).and_then(cached) {
+                Some(report) => report,
+                None => {
+                    uncached.push((index, (day, part, input)));
+                    continue;
+                }
+            },
+        };
+
+        on_event(Event::Finished(&report));
+        reports[index] = Some(report);
+    }
+
+    let queue = Arc::new(Mutex::new(uncached.into_iter()));
+    let running = Arc::new(Mutex::new(Vec::<(usize, Running)>::new()));
+    let (tx, rx) = mpsc::channel();
+
+    for _ in 0..jobs.max(1) {
+        let queue = Arc::clone(&queue);
+        let running = Arc::clone(&running);
+        let tx = tx.clone();
+
+        thread::spawn(move || {
+            let mut left_running: Option<JoinHandle<()>> = None;
+
+            loop {
+                let Some((index, (day, part, input))) = queue.lock().unwrap().next() else {
+                    return;
+                };
+
+                // a solver that timed out still holds this thread's place
+                if let Some(solver) = left_running.take() {
+                    let _ = solver.join();
+                }
+
+                let context = Context::new();
+                running.lock().unwrap().push((
+                    index,
+                    Running {
+                        day: day.number,
+                        part,
+                        context: context.clone(),
+                    },
+                ));
+
+                let (report, solver) = run_part(&day, part, &input, timeout, &context);
+                running.lock().unwrap().retain(|(task, _)| *task != index);
+                left_running = solver;
+
+                if tx.send((index, report)).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    drop(tx);
+
+    loop {
+        match rx.recv_timeout(TICK) {
+            Ok((index, report)) => {
+                if let (Some(cache), Some(key)) = (cache, &keys[index]) {
+                    remember(cache, key, &report);
+                }
+
+                on_event(Event::Finished(&report));
+                reports[index] = Some(report);
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                let running: Vec<Running> = running
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|(_, running)| running.clone())
+                    .collect();
+
+                on_event(Event::Progress(&running));
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    reports.into_iter().flatten().collect()
+}
+
+/// What a part's answer is cached under, or `None` if its day was added
+/// since `aoc` was built.
+fn cache_key(day: &Day, part: u8, input: &Input) -> Option<Key> {
+    Some(Key {
+        day: day.number,
+        part,
+        input_hash: input.hash.clone(),
+        source_hash: cache::source_hash(day.number)?.to_string(),
+    })
+}
+
+/// The report for a cached answer. A cache that cannot be read is warned
+/// about and treated as empty, like the history it is never a reason for a
+/// run to fail.
+fn cached((cache, key): (&Cache, &Key)) -> Option<PartReport> {
+    let hit = cache
+        .get(key)
+        .inspect_err(|error| eprintln!("could not read the answer cache: {error}"))
+        .ok()??;
+
+    Some(PartReport {
+        day: key.day,
+        part: key.part,
+        input_hash: Some(key.input_hash.clone()),
+        outcome: Outcome::Answered(hit.answer),
+        elapsed: hit.elapsed,
+        cached: true,
+    })
+}
+
+/// Caches the report's answer, if it has one.
+fn remember(cache: &Cache, key: &Key, report: &PartReport) {
+    let Outcome::Answered(answer) = &report.outcome else {
+        return;
+    };
+
+    let hit = Hit {
+        answer: answer.clone(),
+        elapsed: report.elapsed,
+    };
+
+    if let Err(error) = cache.put(key, &hit) {
+        eprintln!("could not update the answer cache: {error}");
+    }
+}
+
+/// Solves one part of `day` against `input`, with a solver that reports into
+/// `context` and has it cancelled after `timeout`. A solver that ignores the
+/// cancellation keeps going, and its thread is returned alongside the report
+/// for the caller to wait for before starting another.
+pub fn run_part(
+    day: &Day,
+    part: u8,
+    input: &Input,
+    timeout: Duration,
+    context: &Context,
+) -> (PartReport, Option<JoinHandle<()>>) {
+    let report = |outcome, elapsed| PartReport {
+        day: day.number,
+        part,
+        input_hash: Some(input.hash.clone()),
+        outcome,
+        elapsed,
+        cached: false,
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let number = day.number;
+    let text = Arc::clone(&input.text);
+    let start = Instant::now();
+
+    let solver = {
+        let context = context.clone();
+
+        thread::spawn(move || {
+            let answer = solvers::solve_with(number, part, &text, &context);
+            let _ = tx.send((answer, start.elapsed()));
+        })
+    };
+
+    match rx.recv_timeout(timeout) {
+        Ok((Ok(answer), elapsed)) => (report(Outcome::Answered(answer), elapsed), None),
+        Ok((Err(error), elapsed)) => (report(Outcome::Failed(error.to_string()), elapsed), None),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            context.cancel();
+            (report(Outcome::TimedOut, start.elapsed()), Some(solver))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            let error = "the solver panicked".to_string();
+            (report(Outcome::Failed(error), start.elapsed()), None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::*;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+    }
+
+    const RACES: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    const CALIBRATION: &str = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+    /// A profile with an input for each day in a scratch directory, so the
+    /// tests need neither the real inputs nor the key to unseal them.
+    fn fixtures(test: &str, inputs: &[(&Day, &str)]) -> Profile {
+        let dir = std::env::temp_dir().join(format!("aoc-run-{test}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (day, input) in inputs {
+            fs::write(dir.join(format!("{}.txt", day.name())), input).unwrap();
+        }
+
+        Profile::Named {
+            name: test.to_string(),
+            dir,
+        }
+    }
+
+    fn remove(profile: Profile) {
+        if let Profile::Named { dir, .. } = profile {
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn keeps_the_order_of_the_days() {
+        let days = [
+            Day::find(&root(), 6).unwrap(),
+            Day::find(&root(), 1).unwrap(),
+        ];
+        let profile = fixtures("order", &[(&days[0], RACES), (&days[1], CALIBRATION)]);
+        let mut finished = 0;
+
+        let reports = run_all(&days, &profile, 4, Duration::from_secs(60), None, |event| {
+            if let Event::Finished(_) = event {
+                finished += 1;
+            }
+        });
+
+        assert_eq!(finished, 4);
+        let labels: Vec<(u8, u8)> = reports
+            .iter()
+            .map(|report| (report.day, report.part))
+            .collect();
+        assert_eq!(labels, vec![(6, 1), (6, 2), (1, 1), (1, 2)]);
+        assert!(matches!(&reports[0].outcome, Outcome::Answered(answer) if answer == "288"));
+
+        remove(profile);
+    }
+
+    #[test]
+    fn fails_parts_whose_input_cannot_be_read() {
+        let days = [Day::find(&root(), 6).unwrap()];
+        let profile = fixtures("unreadable", &[(&days[0], "")]);
+        if let Some(path) = profile.input(&days[0], 1) {
+            fs::write(path, b"Time: \xff\n").unwrap();
+        }
+
+        let reports = run_all(&days, &profile, 1, Duration::from_secs(60), None, |_| {});
+
+        assert_eq!(reports.len(), 2);
+        for report in &reports {
+            assert!(matches!(report.outcome, Outcome::Failed(_)), "{report}");
+            assert_eq!(report.input_hash, None);
+        }
+
+        remove(profile);
+    }
+
+    #[test]
+    fn answers_from_the_cache() {
+        let scratch = std::env::temp_dir().join(format!("aoc-run-cache-{}", std::process::id()));
+        let days = [Day::find(&root(), 6).unwrap()];
+        let profile = fixtures("cached", &[(&days[0], RACES)]);
+        let run = |cache: &Cache| {
+            run_all(
+                &days,
+                &profile,
+                2,
+                Duration::from_secs(60),
+                Some(cache),
+                |_| {},
+            )
+        };
+
+        let first = run(&Cache::open(&scratch, true).unwrap());
+        assert!(first.iter().all(|report| !report.cached));
+
+        let second = run(&Cache::open(&scratch, true).unwrap());
+        assert!(second.iter().all(|report| report.cached));
+        assert_eq!(
+            first[1].to_string().split("  (").next(),
+            second[1].to_string().split("  (").next()
+        );
+        assert!(second[1].to_string().ends_with(", cached)"));
+
+        let refreshed = run(&Cache::open(&scratch, false).unwrap());
+        assert!(refreshed.iter().all(|report| !report.cached));
+
+        fs::remove_dir_all(scratch).unwrap();
+        remove(profile);
+    }
+
+    #[test]
+    fn tabulates_answers_per_profile() {
+        let day = Day::find(&root(), 6).unwrap();
+        let report = |part, outcome| PartReport {
+            day: 6,
+            part,
+            input_hash: None,
+            outcome,
+            elapsed: Duration::ZERO,
+            cached: false,
+        };
+        let runs = vec![
+            (
+                "bundled".to_string(),
+                vec![
+                    report(1, Outcome::Answered("393120".to_string())),
+                    report(2, Outcome::TimedOut),
+                ],
+            ),
+            (
+                "ann".to_string(),
+                vec![report(1, Outcome::Answered("288".to_string()))],
+            ),
+        ];
+
+        assert_eq!(
+            table(&[day], &runs),
+            "              bundled    ann
+day-06 part1  393120     288
+day-06 part2  TIMED OUT  -
+"
+        );
+    }
+
+    #[test]
+    fn times_out_distinctly() {
+        let day = Day::find(&root(), 5).unwrap();
+        // large enough that the solver cannot finish before it is waited on
+        let almanac = common::generate::input(5, 0, 2000).unwrap();
+        let profile = fixtures("timeout", &[(&day, &almanac)]);
+        let input = Input::read(&profile.input(&day, 2).unwrap()).unwrap();
+        let context = Context::new();
+        let (report, solver) = run_part(&day, 2, &input, Duration::ZERO, &context);
+
+        assert!(matches!(report.outcome, Outcome::TimedOut), "{report}");
+        assert!(report.to_string().starts_with("day-05 part2  TIMED OUT"));
+        assert!(context.check().is_err());
+
+        // day 5 checks the context, so its solver stops once cancelled
+        solver.expect("the solver is left running").join().unwrap();
+
+        remove(profile);
+    }
+}
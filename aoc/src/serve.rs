@@ -0,0 +1,351 @@
+use std::{
+    io::{self, Read},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::context::Context;
+use serde_json::{json, Value};
+use solvers::Error;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// What the server accepts from a single request.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Largest body accepted, in bytes.
+    pub max_input: usize,
+    /// How long a solver may take before the request gives up on it.
+    pub timeout: Duration,
+    /// How many solvers may run at once. Requests beyond that are refused
+    /// rather than queued.
+    pub jobs: usize,
+}
+
+type Solve = fn(u8, u8, &str, &Context) -> Result<String, Error>;
+
+/// The solvers running, counted so that no more than [`Limits::jobs`] run at
+/// once. A solver that timed out keeps its slot until it actually stops.
+#[derive(Debug, Clone, Default)]
+struct Slots(Arc<AtomicUsize>);
+
+/// A slot taken from [`Slots`], given back when dropped.
+struct Slot(Arc<AtomicUsize>);
+
+impl Slots {
+    fn take(&self, jobs: usize) -> Option<Slot> {
+        self.0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |running| {
+                (running < jobs).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Slot(Arc::clone(&self.0)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Answers `POST /day/{d}/part/{p}` requests on localhost until the process is
+/// stopped. Each request is handled on its own thread, and at most
+/// `limits.jobs` of them solve at a time.
+pub fn serve(port: u16, limits: Limits) -> io::Result<()> {
+    let server = Server::http(("127.0.0.1", port)).map_err(io::Error::other)?;
+    let slots = Slots::default();
+
+    println!("listening on http://{}", server.server_addr());
+
+    for request in server.incoming_requests() {
+        let slots = slots.clone();
+
+        thread::spawn(move || {
+            if let Err(error) = handle(request, limits, &slots) {
+                eprintln!("could not answer a request: {error}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn handle(mut request: Request, limits: Limits, slots: &Slots) -> io::Result<()> {
+    let start = Instant::now();
+    let method = request.method().clone();
+    let url = request.url().to_string();
+
+    let body = match request.body_length() {
+        Some(length) if length > limits.max_input => Err(length),
+        _ => read_limited(request.as_reader(), limits.max_input)?,
+    };
+
+    let (status, mut json) = respond(&method, &url, body, limits, slots, solvers::solve_with);
+
+    if let Some(timings) = json.get_mut("timings") {
+        timings["total_ms"] = json!(millis(start.elapsed()));
+    }
+
+    println!("{method} {url} {status}");
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    request.respond(response)
+}
+
+/// The body, or its length once it turns out to be longer than `max_input`.
+fn read_limited(body: impl Read, max_input: usize) -> io::Result<Result<Vec<u8>, usize>> {
+    let mut bytes = Vec::new();
+    body.take(max_input as u64 + 1).read_to_end(&mut bytes)?;
+
+    if bytes.len() > max_input {
+        return Ok(Err(bytes.len()));
+    }
+
+    Ok(Ok(bytes))
+}
+
+/// The status code and JSON body answering a request. `body` is `Err` with
+/// its length when it was too large to read.
+fn respond(
+    method: &Method,
+    url: &str,
+    body: Result<Vec<u8>, usize>,
+    limits: Limits,
+    slots: &Slots,
+    solve: Solve,
+) -> (u16, Value) {
+    let Some((day, part)) = route(url) else {
+        return error(
+            404,
+            format!("no route for {url}, try POST /day/{{d}}/part/{{p}}"),
+        );
+    };
+
+    if *method != Method::Post {
+        return error(405, format!("{url} only accepts POST"));
+    }
+
+    let body = match body {
+        Ok(body) => body,
+        Err(length) => {
+            let message = format!(
+                "{length} bytes is more than the {} allowed",
+                limits.max_input
+            );
+            return error(413, message);
+        }
+    };
+
+    let Ok(input) = String::from_utf8(body) else {
+        return error(400, "the input is not UTF-8".to_string());
+    };
+
+    let Some(slot) = slots.take(limits.jobs) else {
+        let message = format!("all {} solvers are busy, try again later", limits.jobs);
+        return error(503, message);
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let context = Context::new();
+    let start = Instant::now();
+
+    // solvers that check the context stop soon after a timeout; the others
+    // keep their thread, and their slot, until they finish
+    thread::spawn({
+        let context = context.clone();
+
+        move || {
+            let _ = tx.send(solve(day, part, &input, &context));
+            drop(slot);
+        }
+    });
+
+    let answer = rx.recv_timeout(limits.timeout);
+    let solve_ms = millis(start.elapsed());
+
+    if answer.is_err() {
+        context.cancel();
+    }
+
+    match answer {
+        Ok(Ok(answer)) => (
+            200,
+            json!({
+                "day": day,
+                "part": part,
+                "answer": answer,
+                "timings": { "solve_ms": solve_ms },
+            }),
+        ),
+        Ok(Err(solve_error)) => {
+            let status = match solve_error {
+                Error::UnknownDay(_) | Error::UnknownPart(_) => 404,
+                Error::Solve(_) => 422,
+            };
+
+            error(status, solve_error.to_string())
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            let message = format!("no answer within {} ms", limits.timeout.as_millis());
+            error(504, message)
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => error(500, "the solver panicked".to_string()),
+    }
+}
+
+/// The day and part of a `/day/{d}/part/{p}` path.
+fn route(url: &str) -> Option<(u8, u8)> {
+    let path = url.split('?').next()?;
+    let rest = path.strip_prefix("/day/")?;
+    let (day, part) = rest.split_once("/part/")?;
+
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+fn error(status: u16, message: String) -> (u16, Value) {
+    (status, json!({ "error": message }))
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: Limits = Limits {
+        max_input: 64,
+        timeout: Duration::from_millis(200),
+        jobs: 1,
+    };
+
+    const RACES: &[u8] = b"Time:      7  15   30\nDistance:  9  40  200\n";
+
+    fn post(url: &str, body: &[u8], solve: Solve) -> (u16, Value) {
+        let slots = Slots::default();
+
+        respond(&Method::Post, url, Ok(body.to_vec()), LIMITS, &slots, solve)
+    }
+
+    #[test]
+    fn routes_days_and_parts() {
+        assert_eq!(route("/day/5/part/2"), Some((5, 2)));
+        assert_eq!(route("/day/11/part/1?verbose"), Some((11, 1)));
+        assert_eq!(route("/day/5"), None);
+        assert_eq!(route("/day/five/part/2"), None);
+    }
+
+    #[test]
+    fn answers_with_timings() {
+        let (status, json) = post("/day/6/part/1", RACES, solvers::solve_with);
+
+        assert_eq!(status, 200);
+        assert_eq!(json["answer"], "288");
+        assert_eq!(json["day"], 6);
+        assert!(json["timings"]["solve_ms"].is_f64());
+    }
+
+    #[test]
+    fn rejects_bad_requests() {
+        assert_eq!(post("/day/10/part/1", RACES, solvers::solve_with).0, 404);
+        assert_eq!(post("/day/6/part/3", RACES, solvers::solve_with).0, 404);
+        assert_eq!(post("/days", RACES, solvers::solve_with).0, 404);
+        assert_eq!(
+            post("/day/6/part/1", b"Time: 7\n", solvers::solve_with).0,
+            422
+        );
+        assert_eq!(post("/day/6/part/1", b"\xff", solvers::solve_with).0, 400);
+
+        let get = respond(
+            &Method::Get,
+            "/day/6/part/1",
+            Ok(vec![]),
+            LIMITS,
+            &Slots::default(),
+            solvers::solve_with,
+        );
+        assert_eq!(get.0, 405);
+    }
+
+    #[test]
+    fn limits_the_input_size() {
+        let body = read_limited(&[b'.'; 65][..], 64).unwrap();
+        assert_eq!(body, Err(65));
+
+        let (status, json) = respond(
+            &Method::Post,
+            "/day/6/part/1",
+            body,
+            LIMITS,
+            &Slots::default(),
+            solvers::solve_with,
+        );
+        assert_eq!(status, 413);
+        assert_eq!(json["error"], "65 bytes is more than the 64 allowed");
+
+        assert_eq!(
+            read_limited(&[b'.'; 64][..], 64).unwrap().unwrap().len(),
+            64
+        );
+    }
+
+    #[test]
+    fn cancels_slow_solvers_once_they_time_out() {
+        let slow: Solve = |_, _, _, context| loop {
+            context.check()?;
+            thread::sleep(Duration::from_millis(10));
+        };
+        let slots = Slots::default();
+
+        let (status, json) = respond(
+            &Method::Post,
+            "/day/5/part/2",
+            Ok(RACES.to_vec()),
+            LIMITS,
+            &slots,
+            slow,
+        );
+
+        assert_eq!(status, 504);
+        assert_eq!(json["error"], "no answer within 200 ms");
+
+        // the solver only gives its slot back once it has stopped
+        let start = Instant::now();
+        while slots.take(1).is_none() {
+            assert!(start.elapsed() < Duration::from_secs(2), "still solving");
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn refuses_solves_beyond_the_jobs() {
+        let slots = Slots::default();
+        let slot = slots.take(1).unwrap();
+        let post = || {
+            respond(
+                &Method::Post,
+                "/day/6/part/1",
+                Ok(RACES.to_vec()),
+                LIMITS,
+                &slots,
+                solvers::solve_with,
+            )
+        };
+
+        let (status, json) = post();
+        assert_eq!(status, 503);
+        assert_eq!(json["error"], "all 1 solvers are busy, try again later");
+
+        drop(slot);
+        assert_eq!(post().0, 200);
+    }
+}
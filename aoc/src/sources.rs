@@ -0,0 +1,109 @@
+//! What a day's answers are built from. `build.rs` hashes it into the binary
+//! for `cache::source_hash`, and the tests check that the hash embedded is
+//! the tree's.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+/// The crates beside the days that every day's answers also depend on: the
+/// shared helpers and the registry `aoc` solves through.
+pub const SHARED: [&str; 2] = ["common", "solvers"];
+
+/// The lock file resolving the dependencies compiled into `aoc`, relative to
+/// the root.
+pub const LOCK_FILE: &str = "aoc/Cargo.lock";
+
+/// The SHA-256, in lowercase hex, of the manifest and Rust sources of the day
+/// crate `day` under `root` and of the [`SHARED`] crates, and of the
+/// [`LOCK_FILE`]. Inputs and other files do not count, nor does where the
+/// repository is checked out.
+pub fn hash(root: &Path, day: &str) -> String {
+    let mut files = Vec::new();
+    for krate in [day].into_iter().chain(SHARED) {
+        files.push(root.join(krate).join("Cargo.toml"));
+        rust_sources(&root.join(krate).join("src"), &mut files);
+    }
+    files.push(root.join(LOCK_FILE));
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        let name = file.strip_prefix(root).unwrap_or(&file);
+        hasher.update(name.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(&file).unwrap_or_default());
+        hasher.update([0]);
+    }
+
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Every `.rs` file under `dir`, sorted so the hash does not depend on the
+/// order the file system lists them in.
+fn rust_sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            rust_sources(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_the_sources_but_not_the_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-sources-{}", std::process::id()));
+        let bin = root.join("day-06/src/bin");
+
+        fs::create_dir_all(&bin).unwrap();
+        fs::create_dir_all(root.join("common/src")).unwrap();
+        fs::create_dir_all(root.join("solvers/src")).unwrap();
+        fs::create_dir_all(root.join("aoc")).unwrap();
+        fs::write(root.join("day-06/Cargo.toml"), "[package]").unwrap();
+        fs::write(root.join("day-06/src/lib.rs"), "pub fn part1() {}").unwrap();
+        fs::write(root.join("common/src/lib.rs"), "pub mod math;").unwrap();
+
+        let before = hash(&root, "day-06");
+
+        fs::write(bin.join("input1.txt"), "Time: 7\n").unwrap();
+        assert_eq!(hash(&root, "day-06"), before);
+
+        let mut seen = vec![before];
+        let changes = [
+            ("common/src/lib.rs", "pub mod grid;"),
+            ("solvers/src/lib.rs", "pub mod ffi;"),
+            (LOCK_FILE, "version = 4"),
+            ("day-06/src/bin/part1.rs", "fn main() {}"),
+        ];
+        for (file, contents) in changes {
+            fs::write(root.join(file), contents).unwrap();
+
+            let after = hash(&root, "day-06");
+            assert!(!seen.contains(&after), "{file} did not change the hash");
+            seen.push(after);
+        }
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
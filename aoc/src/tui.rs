@@ -0,0 +1,564 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Cell, Gauge, Paragraph, Row, Table, TableState},
+    DefaultTerminal, Frame,
+};
+
+use common::context::Context;
+
+use crate::{
+    cache, cargo,
+    day::Day,
+    progress::Running,
+    run::{self, Input, Outcome},
+    sources,
+};
+
+/// How long a part solved in-process gets to answer, the same as `aoc run`
+/// gives it by default.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where a day's example tests stand.
+#[derive(Debug, Clone, PartialEq)]
+enum Tests {
+    NotRun,
+    Running,
+    Passed(usize),
+    Failed(usize),
+    BuildFailed,
+}
+
+/// The latest run of one part.
+#[derive(Debug, Clone)]
+enum Part {
+    /// There is no `partN` binary.
+    Missing,
+    NotRun,
+    /// With the solver's progress when it runs in-process.
+    Running {
+        started: Instant,
+        solver: Option<Running>,
+    },
+    Answered {
+        answer: String,
+        elapsed: Duration,
+    },
+    Failed,
+}
+
+#[derive(Debug)]
+struct DayRow {
+    day: Day,
+    tests: Tests,
+    parts: [Part; 2],
+}
+
+impl DayRow {
+    fn new(day: Day) -> DayRow {
+        let available = day.parts();
+        let part = |number| match available.contains(&number) {
+            true => Part::NotRun,
+            false => Part::Missing,
+        };
+
+        DayRow {
+            tests: Tests::NotRun,
+            parts: [part(1), part(2)],
+            day,
+        }
+    }
+
+    fn stars(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| matches!(part, Part::Answered { .. }))
+            .count()
+    }
+}
+
+/// What the worker thread reports back, by index into the rows.
+#[derive(Debug)]
+enum Update {
+    Tests(usize, Tests),
+    Part(usize, u8, Part),
+    /// The worker is done with a day, whatever the outcome.
+    Finished(usize),
+}
+
+/// A day being checked: its steps are the tests, the build and each part.
+#[derive(Debug)]
+struct Job {
+    row: usize,
+    step: usize,
+    steps: usize,
+    started: Instant,
+}
+
+struct App {
+    rows: Vec<DayRow>,
+    table: TableState,
+    queue: Vec<usize>,
+    job: Option<Job>,
+}
+
+impl App {
+    fn new(days: Vec<Day>) -> App {
+        let mut table = TableState::default();
+        table.select((!days.is_empty()).then_some(0));
+
+        App {
+            rows: days.into_iter().map(DayRow::new).collect(),
+            table,
+            queue: Vec::new(),
+            job: None,
+        }
+    }
+
+    fn apply(&mut self, update: Update) {
+        match update {
+            Update::Tests(row, tests) => {
+                self.advance(row, tests != Tests::Running);
+                self.rows[row].tests = tests;
+            }
+            Update::Part(row, part, state) => {
+                // the build is the step before the first part
+                if part == 1 && matches!(state, Part::Running { .. }) {
+                    self.advance(row, true);
+                }
+                self.advance(row, !matches!(state, Part::Running { .. }));
+                self.rows[row].parts[usize::from(part) - 1] = state;
+            }
+            Update::Finished(row) => {
+                if self.job.as_ref().is_some_and(|job| job.row == row) {
+                    self.job = None;
+                }
+
+                self.queue.retain(|&queued| queued != row);
+                if let Some(&next) = self.queue.first() {
+                    self.start(next);
+                }
+            }
+        }
+    }
+
+    fn advance(&mut self, row: usize, done: bool) {
+        match &mut self.job {
+            Some(job) if job.row == row && done => job.step += 1,
+            _ => {}
+        }
+    }
+
+    /// Queues a day to be checked, unless it is already waiting, and tells
+    /// whether it was.
+    fn enqueue(&mut self, row: usize) -> bool {
+        if self.queue.contains(&row) {
+            return false;
+        }
+
+        self.queue.push(row);
+        if self.job.is_none() {
+            self.start(row);
+        }
+
+        true
+    }
+
+    fn start(&mut self, row: usize) {
+        self.job = Some(Job {
+            row,
+            step: 0,
+            steps: 2 + self.rows[row].day.parts().len(),
+            started: Instant::now(),
+        });
+    }
+
+    /// What the solver of the job's running part last reported, if it runs
+    /// in-process.
+    fn solving(&self) -> Option<String> {
+        let job = self.job.as_ref()?;
+
+        self.rows[job.row].parts.iter().find_map(|part| match part {
+            Part::Running {
+                solver: Some(solver),
+                ..
+            } => Some(solver.summary()),
+            _ => None,
+        })
+    }
+
+    fn select(&mut self, offset: isize) {
+        let Some(selected) = self.table.selected() else {
+            return;
+        };
+
+        let last = self.rows.len() as isize - 1;
+        self.table
+            .select(Some((selected as isize + offset).clamp(0, last) as usize));
+    }
+}
+
+/// An interactive overview of every day: which parts exist, whether the
+/// examples pass, and the latest answers and runtimes. Days are checked one at
+/// a time, in the order they were asked for.
+pub fn tui(root: &Path, verbosity: u8) -> io::Result<()> {
+    let mut app = App::new(Day::all(root));
+    let (jobs, updates) = worker(
+        root.to_path_buf(),
+        app.rows.iter().map(|row| row.day.clone()).collect(),
+        verbosity,
+    );
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app, &jobs, &updates);
+    ratatui::restore();
+
+    result
+}
+
+fn run(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    jobs: &Sender<usize>,
+    updates: &Receiver<Update>,
+) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| draw(frame, app))?;
+
+        while let Ok(update) = updates.try_recv() {
+            app.apply(update);
+        }
+
+        // redraw often enough for the running timers to tick
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Down | KeyCode::Char('j') => app.select(1),
+            KeyCode::Up | KeyCode::Char('k') => app.select(-1),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                if let Some(row) = app.table.selected().filter(|&row| app.enqueue(row)) {
+                    let _ = jobs.send(row);
+                }
+            }
+            KeyCode::Char('a') => {
+                for row in 0..app.rows.len() {
+                    if app.enqueue(row) {
+                        let _ = jobs.send(row);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A thread that checks the days it is sent, one at a time, the way `watch`
+/// does: tests first, then every part if they pass. So that answers and tests
+/// never come from different code, the parts are solved in-process, with
+/// their progress shown, only while the day's sources under `root` are the
+/// ones built into `aoc`. Otherwise they run the day's binaries rebuilt from
+/// the sources just tested.
+fn worker(root: PathBuf, days: Vec<Day>, verbosity: u8) -> (Sender<usize>, Receiver<Update>) {
+    let (jobs, queue) = mpsc::channel::<usize>();
+    let (tx, updates) = mpsc::channel();
+
+    thread::spawn(move || {
+        for row in queue {
+            check(&root, &days[row], row, verbosity, &tx);
+
+            if tx.send(Update::Finished(row)).is_err() {
+                return;
+            }
+        }
+    });
+
+    (jobs, updates)
+}
+
+fn check(root: &Path, day: &Day, row: usize, verbosity: u8, tx: &Sender<Update>) {
+    let _ = tx.send(Update::Tests(row, Tests::Running));
+
+    let tests = match cargo::test(day) {
+        Ok(report) if !report.built => Tests::BuildFailed,
+        Ok(report) if !report.is_success() => Tests::Failed(report.failed.len()),
+        Ok(report) => Tests::Passed(report.passed),
+        Err(_) => Tests::BuildFailed,
+    };
+    let passed = matches!(tests, Tests::Passed(_));
+    let _ = tx.send(Update::Tests(row, tests));
+
+    if !passed {
+        return;
+    }
+
+    if cache::source_hash(day.number) == Some(sources::hash(root, &day.name()).as_str()) {
+        for part in day.parts() {
+            solve(day, row, part, tx);
+        }
+        return;
+    }
+
+    // a release build that fails fails every part, since none can run
+    if !cargo::build(day).is_ok_and(|output| output.status.success()) {
+        for part in day.parts() {
+            let _ = tx.send(Update::Part(row, part, Part::Failed));
+        }
+        return;
+    }
+
+    for part in day.parts() {
+        let running = Part::Running {
+            started: Instant::now(),
+            solver: None,
+        };
+        let _ = tx.send(Update::Part(row, part, running));
+
+        let state = match cargo::run(day, part, verbosity) {
+            Ok(cargo::RunReport {
+                answer: Some(answer),
+                elapsed,
+                ..
+            }) => Part::Answered { answer, elapsed },
+            _ => Part::Failed,
+        };
+
+        let _ = tx.send(Update::Part(row, part, state));
+    }
+}
+
+/// Solves a part with the solvers built into `aoc`, as `aoc run` would.
+fn solve(day: &Day, row: usize, part: u8, tx: &Sender<Update>) {
+    let context = Context::new();
+    let running = Part::Running {
+        started: Instant::now(),
+        solver: Some(Running {
+            day: day.number,
+            part,
+            context: context.clone(),
+        }),
+    };
+    let _ = tx.send(Update::Part(row, part, running));
+
+    let state = match day.input(part).map(|path| Input::read(&path)) {
+        Some(Ok(input)) => {
+            let (report, solver) = run::run_part(day, part, &input, TIMEOUT, &context);
+
+            // wait for a solver that ignored the timeout, so that parts still
+            // run one at a time
+            if let Some(solver) = solver {
+                let _ = solver.join();
+            }
+
+            match report.outcome {
+                Outcome::Answered(answer) => Part::Answered {
+                    answer,
+                    elapsed: report.elapsed,
+                },
+                _ => Part::Failed,
+            }
+        }
+        _ => Part::Failed,
+    };
+
+    let _ = tx.send(Update::Part(row, part, state));
+}
+
+fn draw(frame: &mut Frame, app: &mut App) {
+    let [table_area, progress_area, help_area] = Layout::vertical([
+        Constraint::Min(3),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let stars: usize = app.rows.iter().map(DayRow::stars).sum();
+    let header = Row::new(["Day", "Stars", "Tests", "Part 1", "Part 2"])
+        .style(Style::new().add_modifier(Modifier::BOLD));
+    let rows = app.rows.iter().map(|row| {
+        Row::new([
+            Cell::from(row.day.name()),
+            Cell::from("★".repeat(row.stars())).style(Color::Yellow),
+            tests_cell(&row.tests),
+            part_cell(&row.parts[0]),
+            part_cell(&row.parts[1]),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(7),
+            Constraint::Length(6),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(header)
+    .block(Block::bordered().title(format!(" Advent of Code 2023 — {stars} ★ ")))
+    .row_highlight_style(Style::new().reversed());
+
+    frame.render_stateful_widget(table, table_area, &mut app.table);
+
+    let progress = match &app.job {
+        Some(job) => {
+            let mut label = format!(
+                "{}  {}/{}  {:.1?}",
+                app.rows[job.row].day.name(),
+                job.step,
+                job.steps,
+                job.started.elapsed()
+            );
+            if let Some(solving) = app.solving() {
+                label += &format!("  {solving}");
+            }
+
+            Gauge::default()
+                .ratio(job.step.min(job.steps) as f64 / job.steps as f64)
+                .label(label)
+        }
+        None => Gauge::default().ratio(0.0).label("idle"),
+    };
+    let queued = app.queue.len().saturating_sub(1);
+
+    frame.render_widget(
+        progress.block(Block::bordered().title(format!(" Progress — {queued} queued "))),
+        progress_area,
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(
+            " ↑/↓ select   enter run day   a run all   q quit",
+        ))
+        .dim(),
+        help_area,
+    );
+}
+
+fn tests_cell(tests: &Tests) -> Cell<'static> {
+    match tests {
+        Tests::NotRun => Cell::from("·"),
+        Tests::Running => Cell::from("running…").style(Color::Cyan),
+        Tests::Passed(count) => Cell::from(format!("✓ {count}")).style(Color::Green),
+        Tests::Failed(count) => Cell::from(format!("✗ {count}")).style(Color::Red),
+        Tests::BuildFailed => Cell::from("build ✗").style(Color::Red),
+    }
+}
+
+fn part_cell(part: &Part) -> Cell<'static> {
+    match part {
+        Part::Missing => Cell::from("—").dim(),
+        Part::NotRun => Cell::from("·"),
+        Part::Running { started, solver } => {
+            let ratio = solver
+                .as_ref()
+                .and_then(|solver| solver.context.progress().ratio());
+            let text = match ratio {
+                Some(ratio) => format!("running {:.1?} {:.0}%", started.elapsed(), ratio * 100.0),
+                None => format!("running {:.1?}", started.elapsed()),
+            };
+
+            Cell::from(text).style(Color::Cyan)
+        }
+        Part::Answered { answer, elapsed } => Cell::from(format!("{answer}  ({elapsed:.2?})")),
+        Part::Failed => Cell::from("FAILED").style(Color::Red),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+
+    fn app() -> App {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        App::new(Day::all(&root))
+    }
+
+    #[test]
+    fn tracks_a_day_through_its_steps() {
+        let mut app = app();
+        assert!(app.enqueue(4));
+        assert!(app.enqueue(6));
+        assert!(!app.enqueue(4));
+
+        app.apply(Update::Tests(4, Tests::Running));
+        app.apply(Update::Tests(4, Tests::Passed(3)));
+        let context = Context::new();
+        let running = Part::Running {
+            started: Instant::now(),
+            solver: Some(Running {
+                day: 5,
+                part: 1,
+                context: context.clone(),
+            }),
+        };
+        app.apply(Update::Part(4, 1, running));
+        assert_eq!(app.job.as_ref().unwrap().step, 2);
+
+        context.progress().add_total(4);
+        context.progress().advance(1);
+        assert_eq!(app.solving().as_deref(), Some("day-05 part1 25%"));
+
+        let answered = Part::Answered {
+            answer: "218513636".to_string(),
+            elapsed: Duration::from_millis(5),
+        };
+        app.apply(Update::Part(4, 1, answered));
+        app.apply(Update::Part(4, 2, Part::Failed));
+        assert_eq!(app.job.as_ref().unwrap().step, 4);
+        assert_eq!(app.rows[4].stars(), 1);
+        assert_eq!(app.solving(), None);
+
+        app.apply(Update::Finished(4));
+        assert_eq!(app.job.as_ref().unwrap().row, 6);
+        assert_eq!(app.queue, vec![6]);
+    }
+
+    #[test]
+    fn draws_every_day() {
+        let mut app = app();
+        app.rows[0].parts[0] = Part::Answered {
+            answer: "54916".to_string(),
+            elapsed: Duration::from_millis(1),
+        };
+        app.select(-1);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal.draw(|frame| draw(frame, &mut app)).unwrap();
+
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(screen.contains("1 ★"));
+        assert!(screen.contains("day-11"));
+        assert!(screen.contains("54916"));
+        assert_eq!(app.table.selected(), Some(0));
+    }
+}
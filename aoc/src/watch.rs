@@ -0,0 +1,121 @@
+use std::{sync::mpsc, time::Duration};
+
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{EventKind, RecursiveMode},
+    DebounceEventResult,
+};
+
+use crate::{cargo, day::Day};
+
+/// Runs the day once, then again every time its sources, inputs or manifest change.
+pub fn watch(day: &Day, verbosity: u8) -> notify_debouncer_full::notify::Result<()> {
+    let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+    let mut debouncer = new_debouncer(Duration::from_millis(250), None, tx)?;
+
+    debouncer.watch(day.sources(), RecursiveMode::Recursive)?;
+    debouncer.watch(day.manifest(), RecursiveMode::NonRecursive)?;
+
+    println!("watching {} for changes", day.name());
+    check(day, verbosity);
+
+    for result in rx {
+        match result {
+            Ok(events) => {
+                if events.iter().any(|event| is_write(&event.kind)) {
+                    check(day, verbosity);
+                }
+            }
+            Err(errors) => errors
+                .iter()
+                .for_each(|error| eprintln!("watch error: {error}")),
+        }
+    }
+
+    Ok(())
+}
+
+/// cargo and rustc read the watched files too, so only writes should trigger a run.
+fn is_write(kind: &EventKind) -> bool {
+    matches!(
+        kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+/// Runs the example tests and, when they pass, every part against the real input.
+fn check(day: &Day, verbosity: u8) {
+    println!("\n── {} ──", day.name());
+
+    let report = match cargo::test(day) {
+        Ok(report) => report,
+        Err(error) => {
+            println!("  tests  could not run cargo: {error}");
+            return;
+        }
+    };
+
+    if !report.built {
+        println!("  tests  build failed");
+        print_tail(&report.stderr);
+        return;
+    }
+
+    if !report.is_success() {
+        println!(
+            "  tests  FAILED ({} passed, {} failed)",
+            report.passed,
+            report.failed.len()
+        );
+        report
+            .failed
+            .iter()
+            .for_each(|name| println!("         ✗ {name}"));
+        return;
+    }
+
+    println!("  tests  ok ({} passed)", report.passed);
+
+    match cargo::build(day) {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => {
+            println!("  build  failed");
+            print_tail(&String::from_utf8_lossy(&output.stderr));
+            return;
+        }
+        Err(error) => {
+            println!("  build  could not run cargo: {error}");
+            return;
+        }
+    }
+
+    for part in day.parts() {
+        match cargo::run(day, part, verbosity) {
+            Ok(cargo::RunReport {
+                answer: Some(answer),
+                elapsed,
+                stderr,
+            }) => {
+                println!("  part{part}  {answer}  ({:.2?})", elapsed);
+
+                if verbosity > 0 {
+                    stderr.lines().for_each(|line| println!("         {line}"));
+                }
+            }
+            Ok(run) => {
+                println!("  part{part}  FAILED");
+                print_tail(&run.stderr);
+            }
+            Err(error) => println!("  part{part}  could not run cargo: {error}"),
+        }
+    }
+}
+
+fn print_tail(output: &str) {
+    let lines: Vec<&str> = output.lines().collect();
+
+    lines
+        .iter()
+        .skip(lines.len().saturating_sub(10))
+        .for_each(|line| println!("         {line}"));
+}
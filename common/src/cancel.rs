@@ -0,0 +1,60 @@
+//! Cooperative cancellation. The runner cannot stop a solver's thread, so it
+//! flags a token instead, and solvers with long loops check it now and then
+//! and give up with [`SolveError::Cancelled`]. Solvers get the token inside
+//! a [`crate::context::Context`].
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::solve::SolveError;
+
+/// A flag shared between the runner and a solver, and every thread the solver
+/// starts. Clones share the flag.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Asks every holder of the token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// `Err(SolveError::Cancelled)` once the token has been cancelled, for
+    /// solvers to bail out with `?`.
+    pub fn check(&self) -> Result<(), SolveError> {
+        match self.is_cancelled() {
+            true => Err(SolveError::Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_the_flag() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+
+        assert!(clone.check().is_ok());
+
+        token.cancel();
+
+        assert!(clone.is_cancelled());
+        assert!(matches!(clone.check(), Err(SolveError::Cancelled)));
+    }
+}
@@ -0,0 +1,99 @@
+//! What a long-running solver is handed by whoever runs it: a [`CancelToken`]
+//! to notice it should stop, and a [`Progress`] to say how far along it is.
+//! Solvers only report into the context; the runner that made it decides
+//! whether and how the progress is shown.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use crate::{cancel::CancelToken, solve::SolveError};
+
+/// Cancellation and progress for one solver run. Clones share both, so a
+/// solver can hand its context to every thread it starts.
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    cancel: CancelToken,
+    progress: Progress,
+}
+
+impl Context {
+    pub fn new() -> Context {
+        Context::default()
+    }
+
+    /// Asks the solver to stop, see [`CancelToken::cancel`].
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// `Err(SolveError::Cancelled)` once the run has been cancelled, for
+    /// solvers to bail out with `?`.
+    pub fn check(&self) -> Result<(), SolveError> {
+        self.cancel.check()
+    }
+
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+}
+
+/// Units of work done out of a total the solver announces, e.g. walks
+/// finished out of walks started. Totals add up, so a solver can announce
+/// its work a phase at a time.
+#[derive(Debug, Clone, Default)]
+pub struct Progress {
+    done: Arc<AtomicU64>,
+    total: Arc<AtomicU64>,
+}
+
+impl Progress {
+    /// Announces `units` more units of work to come.
+    pub fn add_total(&self, units: u64) {
+        self.total.fetch_add(units, Ordering::Relaxed);
+    }
+
+    /// Reports `units` units of work done.
+    pub fn advance(&self, units: u64) {
+        self.done.fetch_add(units, Ordering::Relaxed);
+    }
+
+    /// The units done and the total announced so far.
+    pub fn counts(&self) -> (u64, u64) {
+        (
+            self.done.load(Ordering::Relaxed),
+            self.total.load(Ordering::Relaxed),
+        )
+    }
+
+    /// How much of the announced work is done, from 0 to 1, or `None` while
+    /// the solver has announced nothing.
+    pub fn ratio(&self) -> Option<f64> {
+        let (done, total) = self.counts();
+
+        (total > 0).then(|| done.min(total) as f64 / total as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_progress_and_cancellation() {
+        let context = Context::new();
+        let clone = context.clone();
+
+        assert_eq!(context.progress().ratio(), None);
+
+        clone.progress().add_total(3);
+        clone.progress().add_total(1);
+        clone.progress().advance(1);
+        assert_eq!(context.progress().counts(), (1, 4));
+        assert_eq!(context.progress().ratio(), Some(0.25));
+
+        context.cancel();
+        assert!(matches!(clone.check(), Err(SolveError::Cancelled)));
+    }
+}
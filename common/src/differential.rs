@@ -0,0 +1,160 @@
+//! Checks an optimised solver against a slow but obviously correct reference on
+//! generated inputs, and reports the smallest input they disagree on.
+//!
+//! Inputs the reference rejects, e.g. a day 8 network where `ZZZ` cannot be
+//! reached, are skipped: there is no right answer for the optimised solver to
+//! miss.
+
+use std::{
+    fmt::{self, Debug},
+    ops::RangeInclusive,
+};
+
+use crate::{generate, solve::SolveError};
+
+/// How many seeds are tried for each size.
+pub const SEEDS: u64 = 64;
+
+/// An input on which the two solvers disagree.
+#[derive(Debug)]
+pub struct Mismatch<T> {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+    /// The generated input, with as many lines removed as possible while the
+    /// solvers still disagree.
+    pub input: String,
+    pub expected: T,
+    pub actual: Result<T, SolveError>,
+}
+
+impl<T: Debug> fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {} solvers disagree on an input shrunk from seed {} and size {}:",
+            self.day, self.seed, self.size
+        )?;
+        writeln!(f, "{}", self.input)?;
+        writeln!(f, "reference: {:?}", self.expected)?;
+
+        match &self.actual {
+            Ok(actual) => write!(f, "optimised: {actual:?}"),
+            Err(error) => write!(f, "optimised: {error}"),
+        }
+    }
+}
+
+/// Runs both solvers on the generated inputs for `day`, smallest size first,
+/// and returns the first disagreement after shrinking it.
+pub fn check<T: PartialEq + Debug>(
+    day: u8,
+    sizes: RangeInclusive<usize>,
+    reference: impl Fn(&str) -> Result<T, SolveError>,
+    optimised: impl Fn(&str) -> Result<T, SolveError>,
+) -> Result<(), Mismatch<T>> {
+    for size in sizes {
+        for seed in 0..SEEDS {
+            let input = generate::input(day, seed, size)
+                .unwrap_or_else(|| panic!("day {day} has no input generator"));
+
+            let Some((expected, actual)) = disagreement(&input, &reference, &optimised) else {
+                continue;
+            };
+
+            let mut mismatch = Mismatch {
+                day,
+                seed,
+                size,
+                input,
+                expected,
+                actual,
+            };
+            shrink(&mut mismatch, &reference, &optimised);
+
+            return Err(mismatch);
+        }
+    }
+
+    Ok(())
+}
+
+/// Both answers, if the reference has one and the optimised solver differs.
+fn disagreement<T: PartialEq>(
+    input: &str,
+    reference: impl Fn(&str) -> Result<T, SolveError>,
+    optimised: impl Fn(&str) -> Result<T, SolveError>,
+) -> Option<(T, Result<T, SolveError>)> {
+    let expected = reference(input).ok()?;
+    let actual = optimised(input);
+
+    match &actual {
+        Ok(answer) if *answer == expected => None,
+        _ => Some((expected, actual)),
+    }
+}
+
+/// Drops lines one at a time for as long as the solvers keep disagreeing.
+/// Candidates that the reference rejects are not well formed, so line removal
+/// never turns the mismatch into a parse error.
+fn shrink<T: PartialEq>(
+    mismatch: &mut Mismatch<T>,
+    reference: impl Fn(&str) -> Result<T, SolveError>,
+    optimised: impl Fn(&str) -> Result<T, SolveError>,
+) {
+    let mut index = 0;
+
+    loop {
+        let lines: Vec<&str> = mismatch.input.lines().collect();
+
+        if index >= lines.len() {
+            return;
+        }
+
+        let mut candidate: String = lines
+            .iter()
+            .enumerate()
+            .filter(|&(line, _)| line != index)
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n");
+        candidate.push('\n');
+
+        match disagreement(&candidate, &reference, &optimised) {
+            Some((expected, actual)) => {
+                mismatch.input = candidate;
+                mismatch.expected = expected;
+                mismatch.actual = actual;
+            }
+            None => index += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_count(input: &str) -> Result<usize, SolveError> {
+        Ok(input.lines().count())
+    }
+
+    #[test]
+    fn agreeing_solvers_pass() {
+        assert!(check(9, 1..=5, line_count, line_count).is_ok());
+    }
+
+    #[test]
+    fn reports_a_shrunk_mismatch() {
+        // wrong as soon as a history has a negative reading
+        let no_negatives =
+            |input: &str| Ok(input.lines().filter(|line| !line.contains('-')).count());
+
+        let mismatch = check(9, 1..=5, line_count, no_negatives).unwrap_err();
+
+        assert_eq!(mismatch.input.lines().count(), 1);
+        assert!(mismatch.input.contains('-'));
+        assert_eq!(mismatch.expected, 1);
+        assert_eq!(mismatch.actual.unwrap(), 0);
+    }
+}
@@ -0,0 +1,338 @@
+//! Random but well-formed puzzle inputs, for stress tests, property tests and
+//! benchmarks. The same day, seed and size always give the same input.
+//!
+//! `size` is the number of records a day's input is made of: lines for most
+//! days, races for day 6, nodes for day 8 and the side of the grid for days 3
+//! and 11. Small sizes are where the edge cases live, e.g. day 9 histories of a
+//! single reading or day 11 images without galaxies.
+
+use std::{collections::HashSet, fmt::Write};
+
+use rand::{
+    seq::{IndexedRandom, SliceRandom},
+    Rng, SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+/// The days that have a generator.
+pub const DAYS: [u8; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 11];
+
+/// An input for `day`, or `None` if the day has no generator. Puzzle inputs
+/// are never empty, so a `size` of 0 gives the same input as 1.
+pub fn input(day: u8, seed: u64, size: usize) -> Option<String> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let rng = &mut rng;
+    let size = size.max(1);
+
+    let input = match day {
+        1 => calibration_document(rng, size),
+        2 => games(rng, size),
+        3 => engine_schematic(rng, size),
+        4 => scratchcards(rng, size),
+        5 => almanac(rng, size),
+        6 => races(rng, size),
+        7 => camel_cards(rng, size),
+        8 => network(rng, size),
+        9 => oasis_report(rng, size),
+        11 => galaxy_image(rng, size),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Lines of letters, digits and spelled out digits, each with at least one
+/// real digit so both parts have an answer.
+fn calibration_document(rng: &mut impl Rng, size: usize) -> String {
+    let mut document = String::new();
+
+    for _ in 0..size {
+        let mut line = String::new();
+
+        for _ in 0..rng.random_range(0..6) {
+            match rng.random_range(0..3) {
+                0 => line.push(rng.random_range(b'1'..=b'9') as char),
+                1 => line.push_str(DIGIT_WORDS.choose(rng).unwrap()),
+                _ => line.push(rng.random_range(b'a'..=b'z') as char),
+            }
+        }
+
+        let digit = rng.random_range(b'1'..=b'9') as char;
+        line.insert(rng.random_range(0..=line.len()), digit);
+
+        writeln!(document, "{line}").unwrap();
+    }
+
+    document
+}
+
+fn games(rng: &mut impl Rng, size: usize) -> String {
+    let mut record = String::new();
+
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.random_range(1..=5))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                colors.shuffle(rng);
+
+                colors[..rng.random_range(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.random_range(1..=20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+
+        writeln!(record, "Game {id}: {}", rounds.join("; ")).unwrap();
+    }
+
+    record
+}
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// A square grid of numbers of one to three digits scattered between symbols,
+/// with a `.` or symbol after every number so that neighbours never merge.
+fn engine_schematic(rng: &mut impl Rng, size: usize) -> String {
+    let mut schematic = String::new();
+
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+
+        while row.len() < size {
+            let room = size - row.len();
+
+            if room > 1 && rng.random_bool(0.25) {
+                for _ in 0..rng.random_range(1..room.min(4)) {
+                    row.push(rng.random_range(b'0'..=b'9'));
+                }
+
+                row.push(b'.');
+            } else if rng.random_bool(0.1) {
+                row.push(*SYMBOLS.choose(rng).unwrap());
+            } else {
+                row.push(b'.');
+            }
+        }
+
+        schematic.extend(row.into_iter().map(char::from));
+        schematic.push('\n');
+    }
+
+    schematic
+}
+
+fn scratchcards(rng: &mut impl Rng, size: usize) -> String {
+    let mut pile = String::new();
+
+    for id in 1..=size {
+        let winning = distinct_numbers(rng, 5);
+        let have = distinct_numbers(rng, 8);
+
+        writeln!(pile, "Card {id:>3}: {winning} | {have}").unwrap();
+    }
+
+    pile
+}
+
+/// `count` different numbers from 1 to 99, right aligned in two columns.
+fn distinct_numbers(rng: &mut impl Rng, count: usize) -> String {
+    rand::seq::index::sample(rng, 99, count)
+        .into_iter()
+        .map(|number| format!("{:>2}", number + 1))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+const ALMANAC_MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// `size` seed ranges, and `size` lines in each map. Values stay far enough
+/// below `u32::MAX` that no range or translation overflows.
+fn almanac(rng: &mut impl Rng, size: usize) -> String {
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let (start, length) = span(rng);
+            format!("{start} {length}")
+        })
+        .collect();
+
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in ALMANAC_MAPS {
+        write!(almanac, "\n{name} map:\n").unwrap();
+
+        for _ in 0..size {
+            let destination = rng.random_range(0..1_000_000_u64);
+            let (source, length) = span(rng);
+
+            writeln!(almanac, "{destination} {source} {length}").unwrap();
+        }
+    }
+
+    almanac
+}
+
+/// The start and length of a range in an almanac.
+fn span(rng: &mut impl Rng) -> (u64, u64) {
+    (rng.random_range(0..1_000_000), rng.random_range(1..1_000))
+}
+
+/// Races that can all be won. Part 2 joins the numbers of every race, so it
+/// only has an answer that fits in a `u64` for a handful of races.
+fn races(rng: &mut impl Rng, size: usize) -> String {
+    let (times, records): (Vec<u64>, Vec<u64>) = (0..size)
+        .map(|_| {
+            let time = rng.random_range(2..100);
+            let best = (time / 2) * (time - time / 2);
+
+            (time, rng.random_range(0..best))
+        })
+        .unzip();
+
+    let row = |numbers: Vec<u64>| {
+        numbers
+            .iter()
+            .map(|number| format!("{number:>5}"))
+            .collect::<String>()
+    };
+
+    format!("Time:    {}\nDistance:{}\n", row(times), row(records))
+}
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+fn camel_cards(rng: &mut impl Rng, size: usize) -> String {
+    let mut hands = String::new();
+    let mut dealt = HashSet::new();
+
+    // the hands are distinct, as in the real inputs, so there are only so many
+    while dealt.len() < size.min(CARDS.len().pow(5)) {
+        // favouring a few cards makes pairs and full houses common enough
+        let variety = rng.random_range(2..=CARDS.len());
+        let cards: Vec<u8> = CARDS.choose_multiple(rng, variety).copied().collect();
+        let hand: String = (0..5)
+            .map(|_| *cards.choose(rng).unwrap() as char)
+            .collect();
+
+        if dealt.insert(hand.clone()) {
+            writeln!(hands, "{hand} {}", rng.random_range(1..=1000)).unwrap();
+        }
+    }
+
+    hands
+}
+
+/// `size` nodes, at least `AAA` and `ZZZ`, linked at random. Nothing
+/// guarantees `ZZZ` is reachable from `AAA`, nor every `..A` node a `..Z` one.
+/// There are only so many three letter names, so `size` is capped at those.
+fn network(rng: &mut impl Rng, size: usize) -> String {
+    let mut names = vec!["AAA".to_string(), "ZZZ".to_string()];
+    let mut taken: HashSet<String> = names.iter().cloned().collect();
+
+    while names.len() < size.clamp(2, 26_usize.pow(3)) {
+        let name: String = (0..3)
+            .map(|_| rng.random_range(b'A'..=b'Z') as char)
+            .collect();
+
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    names[1..].shuffle(rng);
+
+    let instructions: String = (0..rng.random_range(1..=size))
+        .map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+    let mut network = format!("{instructions}\n\n");
+
+    for name in &names {
+        let left = names.choose(rng).unwrap();
+        let right = names.choose(rng).unwrap();
+
+        writeln!(network, "{name} = ({left}, {right})").unwrap();
+    }
+
+    network
+}
+
+/// Histories sampled from polynomials of low degree, like the real ones, with
+/// between one and twenty one readings each.
+fn oasis_report(rng: &mut impl Rng, size: usize) -> String {
+    let mut report = String::new();
+
+    for _ in 0..size {
+        let length: i64 = rng.random_range(1..=21);
+        let coefficients: Vec<i64> = (0..rng.random_range(1..=length.min(5)))
+            .map(|_| rng.random_range(-5..=5))
+            .collect();
+        let readings: Vec<String> = (0..length)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+
+        writeln!(report, "{}", readings.join(" ")).unwrap();
+    }
+
+    report
+}
+
+/// A square image with roughly one galaxy in ten cells, possibly none.
+fn galaxy_image(rng: &mut impl Rng, size: usize) -> String {
+    let mut image = String::new();
+
+    for _ in 0..size {
+        image.extend((0..size).map(|_| if rng.random_bool(0.1) { '#' } else { '.' }));
+        image.push('\n');
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_deterministic() {
+        for day in DAYS {
+            assert_eq!(input(day, 7, 12), input(day, 7, 12));
+            assert_ne!(input(day, 7, 12), input(day, 8, 12), "day {day}");
+        }
+
+        assert_eq!(input(10, 7, 12), None);
+    }
+
+    #[test]
+    fn sizes_count_records() {
+        assert_eq!(input(9, 1, 4).unwrap().lines().count(), 4);
+        assert_eq!(input(9, 1, 0), input(9, 1, 1));
+        assert!(input(11, 1, 3).unwrap().lines().all(|row| row.len() == 3));
+        assert_eq!(input(8, 1, 5).unwrap().lines().count(), 2 + 5);
+        assert!(input(8, 1, 0).unwrap().contains("ZZZ = ("));
+    }
+
+    #[test]
+    fn caps_sizes_at_the_distinct_records() {
+        let nodes = 26_usize.pow(3);
+
+        assert_eq!(input(8, 1, nodes + 1).unwrap().lines().count(), 2 + nodes);
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A cell position, counted from the top left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, col: usize) -> Point {
+        Point { row, col }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+/// A maximal horizontal stretch of cells, `start..end` within `row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.row == self.row && (self.start..self.end).contains(&point.col)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start..self.end).map(|col| Point::new(self.row, col))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A line had a different length to the first one.
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The cell parser rejected a character.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} cells wide, expected {expected} like the first line"
+            ),
+            GridError::InvalidCell {
+                line,
+                column,
+                found,
+            } => write!(f, "unexpected {found:?} at line {line}, column {column}"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular map of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    pub fn from_chars(input: &str) -> Result<Grid<char>, GridError> {
+        Grid::parse(input, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from a character map, one row per line, converting every
+    /// character with `cell`. Trailing blank lines are ignored. Line and column
+    /// numbers in errors start at 1.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (line_index, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let row_start = cells.len();
+
+            for (col_index, char) in line.chars().enumerate() {
+                let value = cell(char).ok_or(GridError::InvalidCell {
+                    line: line_index + 1,
+                    column: col_index + 1,
+                    found: char,
+                })?;
+
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            let expected = *width.get_or_insert(row_width);
+
+            if row_width != expected {
+                return Err(GridError::RaggedRow {
+                    line: line_index + 1,
+                    expected,
+                    found: row_width,
+                });
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        self.cells.get(point.row * self.width + point.col)
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        self.cells.get_mut(point.row * self.width + point.col)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.height {
+            return None;
+        }
+
+        Some(&self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).filter_map(move |row| self.get(&Point::new(row, col)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (Point::new(index / self.width, index % self.width), cell))
+    }
+
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter_map(move |(point, cell)| predicate(cell).then_some(point))
+    }
+
+    /// The up to four orthogonally adjacent points inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent points inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// Finds every maximal horizontal run of cells matching `predicate`, row by row.
+    pub fn runs(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Run> {
+        let mut runs = Vec::new();
+
+        for (row_index, row) in self.rows().enumerate() {
+            let mut start = None;
+
+            for (col_index, cell) in row.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(col_index),
+                    (false, Some(run_start)) => {
+                        runs.push(Run {
+                            row: row_index,
+                            start: run_start,
+                            end: col_index,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(run_start) = start {
+                runs.push(Run {
+                    row: row_index,
+                    start: run_start,
+                    end: self.width,
+                });
+            }
+        }
+
+        runs
+    }
+
+    /// The points surrounding a run, including diagonals, each reported once.
+    pub fn run_neighbours<'a>(&'a self, run: &'a Run) -> impl Iterator<Item = Point> + 'a {
+        let rows = run.row.saturating_sub(1)..=run.row + 1;
+
+        rows.flat_map(move |row| {
+            (run.start.saturating_sub(1)..=run.end).map(move |col| Point::new(row, col))
+        })
+        .filter(move |point| self.contains(point) && !run.contains(point))
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    fn offset(&self, point: Point, (row_offset, col_offset): (isize, isize)) -> Option<Point> {
+        let neighbour = Point::new(
+            point.row.checked_add_signed(row_offset)?,
+            point.col.checked_add_signed(col_offset)?,
+        );
+
+        self.contains(&neighbour).then_some(neighbour)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(&point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(&point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.";
+
+    #[test]
+    fn parses_char_maps() {
+        let grid = Grid::from_chars(EXAMPLE).unwrap();
+
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[Point::new(1, 3)], '*');
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid.get(&Point::new(0, 10)), None);
+        assert_eq!(
+            grid.row(2).unwrap().iter().collect::<String>(),
+            "..35..633."
+        );
+        assert_eq!(grid.column(2).collect::<String>(), "7.3");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(
+            Grid::from_chars("...\n..\n..."),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse("..\n.x", |c| (c == '.').then_some(())),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!(Grid::from_chars("").unwrap().height(), 0);
+        assert_eq!(Grid::from_chars("..\n..\n\n").unwrap().height(), 2);
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = Grid::from_chars(EXAMPLE).unwrap();
+
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 3)).count(), 8);
+        assert_eq!(grid.neighbours4(Point::new(2, 9)).count(), 2);
+    }
+
+    #[test]
+    fn finds_runs_and_their_neighbours() {
+        let grid = Grid::from_chars(EXAMPLE).unwrap();
+        let runs = grid.runs(char::is_ascii_digit);
+
+        assert_eq!(
+            runs,
+            vec![
+                Run {
+                    row: 0,
+                    start: 0,
+                    end: 3
+                },
+                Run {
+                    row: 0,
+                    start: 5,
+                    end: 8
+                },
+                Run {
+                    row: 2,
+                    start: 2,
+                    end: 4
+                },
+                Run {
+                    row: 2,
+                    start: 6,
+                    end: 9
+                },
+            ]
+        );
+        assert_eq!(grid.run_neighbours(&runs[0]).count(), 5);
+        assert_eq!(grid.run_neighbours(&runs[2]).count(), 6);
+        assert!(grid
+            .run_neighbours(&runs[2])
+            .any(|point| grid[point] == '*'));
+    }
+
+    #[test]
+    fn transposes() {
+        let grid = Grid::from_chars("ab\ncd\nef").unwrap();
+        let transposed = grid.transpose();
+
+        assert_eq!(transposed, Grid::from_chars("ace\nbdf").unwrap());
+        assert_eq!(transposed.transpose(), grid);
+    }
+}
@@ -0,0 +1,220 @@
+//! Puzzle inputs as they arrive from browsers, editors and other operating
+//! systems: with CRLF line endings, trailing spaces, and no, one or several
+//! newlines at the end. Every day normalises its input before parsing, so the
+//! parsers only ever see the one shape.
+//!
+//! Days whose lines stand alone can also stream their input from a reader
+//! with [`for_each_line`], in constant memory however large it is.
+
+use std::{
+    borrow::Cow,
+    io::{self, BufRead, Read},
+};
+
+use nom_supreme::final_parser::Location;
+
+use crate::{parsing::ParseError, solve::SolveError};
+
+/// `input` with `\n` line endings, no whitespace at the end of any line, no
+/// blank lines at the end and exactly one final newline, unless it is empty.
+/// Blank lines between sections survive, emptied of any spaces.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    if is_normal(input) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalised = String::with_capacity(input.len() + 1);
+
+    for line in input.lines() {
+        normalised.push_str(line.trim_end());
+        normalised.push('\n');
+    }
+
+    let end = normalised.trim_end_matches('\n').len();
+    normalised.truncate(end);
+
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    Cow::Owned(normalised)
+}
+
+fn is_normal(input: &str) -> bool {
+    if input.is_empty() {
+        return true;
+    }
+
+    input.ends_with('\n')
+        && !input.ends_with("\n\n")
+        && !input.contains('\r')
+        && input
+            .split('\n')
+            .all(|line| line.trim_end().len() == line.len())
+}
+
+/// Calls `each` with every line of `reader`, normalised the way [`normalise`]
+/// would: without its line ending or trailing whitespace, and without the
+/// blank lines at the end. One buffer is reused for every line.
+///
+/// `each` parses a single line, so the line numbers in its parse errors are
+/// moved to where the line is in the whole input.
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut each: impl FnMut(&str) -> Result<(), SolveError>,
+) -> Result<(), SolveError> {
+    let mut buffer = String::new();
+    let mut number = 0;
+    // blank lines only count once a line follows them
+    let mut blank_lines = 0;
+
+    loop {
+        buffer.clear();
+
+        if reader.read_line(&mut buffer).map_err(SolveError::Read)? == 0 {
+            return Ok(());
+        }
+
+        let line = buffer.trim_end();
+
+        if line.is_empty() {
+            blank_lines += 1;
+            continue;
+        }
+
+        for _ in 0..blank_lines {
+            number += 1;
+            each("").map_err(|error| on_line(error, number))?;
+        }
+        blank_lines = 0;
+
+        number += 1;
+        each(line).map_err(|error| on_line(error, number))?;
+    }
+}
+
+fn on_line(error: SolveError, number: usize) -> SolveError {
+    match error {
+        SolveError::Parse(ParseError(tree)) => {
+            SolveError::Parse(ParseError(tree.map_locations(|location| Location {
+                line: location.line + number - 1,
+                column: location.column,
+            })))
+        }
+        error => error,
+    }
+}
+
+/// A reader over `lines`, made one at a time as it is read, for streaming
+/// inputs far larger than memory.
+pub fn reader(lines: impl Iterator<Item = String>) -> impl BufRead {
+    io::BufReader::new(LinesReader {
+        lines,
+        current: Vec::new(),
+        position: 0,
+    })
+}
+
+struct LinesReader<I> {
+    lines: I,
+    current: Vec<u8>,
+    position: usize,
+}
+
+impl<I: Iterator<Item = String>> Read for LinesReader<I> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.current.len() {
+            let Some(line) = self.lines.next() else {
+                return Ok(0);
+            };
+
+            self.current = line.into_bytes();
+            self.current.push(b'\n');
+            self.position = 0;
+        }
+
+        let rest = &self.current[self.position..];
+        let count = rest.len().min(buf.len());
+
+        buf[..count].copy_from_slice(&rest[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+/// `input` mangled in each of the ways [`normalise`] undoes, named for test
+/// failure messages. Solvers should answer every variant the same.
+pub fn variants(input: &str) -> Vec<(&'static str, String)> {
+    let trimmed = input.trim_end_matches('\n');
+
+    vec![
+        ("as given", input.to_string()),
+        (
+            "CRLF line endings",
+            format!("{trimmed}\n").replace('\n', "\r\n"),
+        ),
+        ("no final newline", trimmed.to_string()),
+        ("extra final newlines", format!("{trimmed}\n\n\n")),
+        (
+            "trailing spaces",
+            trimmed
+                .lines()
+                .map(|line| format!("{line}  \t\n"))
+                .collect(),
+        ),
+        (
+            "everything at once",
+            format!("{}\r\n\r\n", trimmed.replace('\n', " \r\n")),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undoes_every_variant() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n";
+
+        for (variant, mangled) in variants(input) {
+            assert_eq!(normalise(&mangled), input, "{variant}");
+        }
+    }
+
+    #[test]
+    fn streams_normalised_lines() {
+        let input = "a \r\n\nb\r\n\r\n\n";
+        let mut lines = Vec::new();
+
+        for_each_line(input.as_bytes(), |line| {
+            lines.push(line.to_string());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(lines, normalise(input).lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn moves_parse_errors_to_their_line() {
+        let input = reader(["1 2", "3 4", "5 x"].map(String::from).into_iter());
+
+        let error = for_each_line(input, |line| {
+            crate::parsing::parse_all(line, crate::parsing::numbers::<u8>)?;
+            Ok(())
+        })
+        .unwrap_err();
+
+        assert!(error.to_string().contains("line 3, column 3"), "{error}");
+    }
+
+    #[test]
+    fn borrows_normal_input() {
+        assert!(matches!(normalise("a\n\nb\n"), Cow::Borrowed(_)));
+        assert!(matches!(normalise(""), Cow::Borrowed(_)));
+        assert_eq!(normalise("\n\n"), "");
+        assert_eq!(normalise("  a\r\n"), "  a\n");
+    }
+}
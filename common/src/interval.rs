@@ -0,0 +1,388 @@
+//! Sets of integers stored as sorted, disjoint half-open ranges, and maps that
+//! translate whole ranges at once, so puzzles about huge spans of numbers never
+//! have to visit the numbers one by one.
+
+use std::ops::Range;
+
+use num_traits::PrimInt;
+
+/// A set of integers kept as sorted ranges that neither overlap nor touch, so
+/// two sets holding the same values always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set, or `None` if that does not fit in `T`.
+    pub fn len(&self) -> Option<T> {
+        self.ranges.iter().try_fold(T::zero(), |total, range| {
+            total.checked_add(&(range.end - range.start))
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    /// The normalised ranges, in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&IntervalSet::from(range));
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.ranges().chain(other.ranges()).collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (self.ranges.iter(), other.ranges.iter());
+        let (mut current_left, mut current_right) = (left.next(), right.next());
+
+        while let (Some(a), Some(b)) = (current_left, current_right) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            if a.end <= b.end {
+                current_left = left.next();
+            } else {
+                current_right = right.next();
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The values in `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut removed = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            while let Some(hole) = removed.peek() {
+                if hole.end <= start {
+                    removed.next();
+                    continue;
+                }
+
+                if hole.start >= range.end {
+                    break;
+                }
+
+                if hole.start > start {
+                    ranges.push(start..hole.start);
+                }
+
+                if hole.end >= range.end {
+                    start = range.end;
+                    break;
+                }
+
+                start = hole.end;
+                removed.next();
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// Moves every value by `to - from`, e.g. `shift(98, 50)` sends `98..100` to
+    /// `50..52`, or `None` if a shifted range does not fit in `T`. Taking two
+    /// points rather than a signed delta keeps this usable for unsigned `T`.
+    pub fn shift(&self, from: T, to: T) -> Option<IntervalSet<T>> {
+        let shift_value = |value: T| {
+            if to >= from {
+                value.checked_add(&(to - from))
+            } else {
+                value.checked_sub(&(from - to))
+            }
+        };
+
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| Some(shift_value(range.start)?..shift_value(range.end)?))
+            .collect::<Option<_>>()?;
+
+        Some(IntervalSet { ranges })
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    /// Sorts the ranges and merges those that overlap or touch. Empty ranges
+    /// are dropped.
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<T>> = iter
+            .into_iter()
+            .filter(|range| range.start < range.end)
+            .collect();
+        sorted.sort_by_key(|range| range.start);
+
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+/// A piecewise translation: values inside a source range move to the same
+/// offset within its destination range, everything else maps to itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    /// `(source, destination start)`, sorted by source and never overlapping.
+    entries: Vec<(Range<T>, T)>,
+    domain: IntervalSet<T>,
+}
+
+impl<T: PrimInt> Default for RangeMap<T> {
+    fn default() -> Self {
+        RangeMap::new()
+    }
+}
+
+impl<T: PrimInt> RangeMap<T> {
+    pub fn new() -> RangeMap<T> {
+        RangeMap {
+            entries: Vec::new(),
+            domain: IntervalSet::new(),
+        }
+    }
+
+    /// Maps `source` onto the range of the same length starting at
+    /// `destination`. Values already covered by an earlier entry keep their
+    /// mapping, so the first matching line of a puzzle input wins.
+    ///
+    /// Returns `None`, leaving the map as it was, if the destination range
+    /// does not fit in `T`.
+    pub fn insert(&mut self, source: Range<T>, destination: T) -> Option<()> {
+        if source.start < source.end {
+            destination.checked_add(&(source.end - source.start))?;
+        }
+
+        let uncovered = IntervalSet::from(source.clone()).difference(&self.domain);
+
+        for range in uncovered.ranges() {
+            let offset = range.start - source.start;
+            self.entries.push((range, destination + offset));
+        }
+
+        self.entries.sort_by_key(|(range, _)| range.start);
+        self.domain = self.domain.union(&IntervalSet::from(source));
+
+        Some(())
+    }
+
+    /// A map with every entry inserted in order, or `None` if one does not
+    /// fit, see [`RangeMap::insert`].
+    pub fn from_entries(entries: impl IntoIterator<Item = (Range<T>, T)>) -> Option<RangeMap<T>> {
+        let mut map = RangeMap::new();
+
+        for (source, destination) in entries {
+            map.insert(source, destination)?;
+        }
+
+        Some(map)
+    }
+
+    /// The source values that are translated, as opposed to mapping to themselves.
+    pub fn domain(&self) -> &IntervalSet<T> {
+        &self.domain
+    }
+
+    /// Where `value` is sent. `None` never happens for a map built with
+    /// [`RangeMap::insert`], which refuses entries that would not fit.
+    pub fn get(&self, value: T) -> Option<T> {
+        let index = self
+            .entries
+            .partition_point(|(source, _)| source.end <= value);
+
+        match self.entries.get(index) {
+            Some((source, destination)) if source.start <= value => {
+                destination.checked_add(&(value - source.start))
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Every value of `set` sent through the map, computed range by range, or
+    /// `None` if a mapped range does not fit in `T`, as for [`RangeMap::get`].
+    pub fn map(&self, set: &IntervalSet<T>) -> Option<IntervalSet<T>> {
+        let mut ranges = set.difference(&self.domain).ranges;
+
+        for (source, destination) in &self.entries {
+            let mapped = set
+                .intersection(&IntervalSet::from(source.clone()))
+                .shift(source.start, *destination)?;
+
+            ranges.extend(mapped.ranges);
+        }
+
+        Some(ranges.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn set(ranges: &[Range<u32>]) -> IntervalSet<u32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn values(set: &IntervalSet<u32>) -> BTreeSet<u32> {
+        set.ranges().flatten().collect()
+    }
+
+    #[test]
+    fn normalises_ranges() {
+        let normalised = set(&[10..12, 0..3, 2..5, 5..6, 8..8]);
+
+        assert_eq!(normalised.ranges().collect::<Vec<_>>(), vec![0..6, 10..12]);
+        assert_eq!(normalised.len(), Some(8));
+        assert_eq!(normalised.min(), Some(0));
+        assert_eq!(normalised.max(), Some(11));
+        assert!(normalised.contains(5));
+        assert!(!normalised.contains(6));
+        assert!(set(&[]).is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = IntervalSet::from(5..25);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..30]));
+        assert_eq!(b.difference(&a), IntervalSet::from(10..20));
+        assert_eq!(a.shift(0, 5), Some(set(&[5..15, 25..35])));
+        assert_eq!(
+            IntervalSet::from(98..100).shift(98, 50),
+            Some(IntervalSet::from(50..52))
+        );
+        assert_eq!(a.shift(5, 0), None);
+        assert_eq!(a.shift(0, u32::MAX - 10), None);
+    }
+
+    #[test]
+    fn maps_ranges_piecewise() {
+        let seed_to_soil = RangeMap::<u32>::from_entries([(98..100, 50), (50..98, 52)]).unwrap();
+
+        assert_eq!(seed_to_soil.get(79), Some(81));
+        assert_eq!(seed_to_soil.get(99), Some(51));
+        assert_eq!(seed_to_soil.get(13), Some(13));
+        assert_eq!(
+            seed_to_soil.map(&set(&[79..93, 55..68, 96..102])),
+            Some(set(&[50..52, 57..70, 81..95, 98..100, 100..102]))
+        );
+
+        let overlapping = RangeMap::<u32>::from_entries([(0..10, 100), (5..15, 200)]).unwrap();
+
+        assert_eq!(overlapping.get(7), Some(107));
+        assert_eq!(overlapping.get(12), Some(207));
+        assert_eq!(overlapping.domain(), &IntervalSet::from(0..15));
+    }
+
+    #[test]
+    fn refuses_entries_that_do_not_fit() {
+        let mut map = RangeMap::<u32>::from_entries([(0..10, 100)]).unwrap();
+        let before = map.clone();
+
+        assert_eq!(map.insert(20..30, u32::MAX - 5), None);
+        assert_eq!(map, before);
+        assert_eq!(map.insert(20..30, u32::MAX - 10), Some(()));
+        assert_eq!(map.get(29), Some(u32::MAX - 1));
+        assert_eq!(RangeMap::from_entries([(0..2, u32::MAX)]), None);
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<u32>>> {
+        prop::collection::vec(
+            (0_u32..60, 0_u32..15).prop_map(|(start, len)| start..start + len),
+            0..6,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn operations_match_btree_sets(a in ranges(), b in ranges()) {
+            let (a, b) = (set(&a), set(&b));
+            let (a_values, b_values) = (values(&a), values(&b));
+
+            prop_assert_eq!(values(&a.union(&b)), &a_values | &b_values);
+            prop_assert_eq!(values(&a.intersection(&b)), &a_values & &b_values);
+            prop_assert_eq!(values(&a.difference(&b)), &a_values - &b_values);
+            prop_assert_eq!(a.len(), Some(a_values.len() as u32));
+        }
+
+        #[test]
+        fn map_matches_pointwise_get(
+            entries in prop::collection::vec((0_u32..60, 0_u32..15, 0_u32..100), 0..5),
+            input in ranges(),
+        ) {
+            let map = RangeMap::from_entries(
+                entries
+                    .into_iter()
+                    .map(|(start, len, destination)| (start..start + len, destination)),
+            )
+            .unwrap();
+            let input = set(&input);
+            let pointwise: BTreeSet<u32> = values(&input).into_iter().map(|value| map.get(value).unwrap()).collect();
+
+            prop_assert_eq!(values(&map.map(&input).unwrap()), pointwise);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Building blocks shared between the `day-NN` crates.
+
+pub mod cancel;
+pub mod context;
+pub mod differential;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod logging;
+pub mod math;
+pub mod parsing;
+pub mod secret;
+pub mod solve;
+pub mod validate;
@@ -0,0 +1,123 @@
+//! Logging for the solver binaries, silent unless the `AOC_LOG` environment
+//! variable asks for it. The runner sets it from its `-v` flags, or installs
+//! the subscriber itself with [`init_all`] when it solves in-process.
+//!
+//! `AOC_LOG` holds comma separated directives: a bare level applies to every
+//! day, `day-05=debug` only to that day's crate, e.g. `warn,day-05=trace`.
+//! Events go to stderr so the answer on stdout stays the only thing printed there.
+
+use std::{
+    collections::BTreeSet,
+    io::{self, IsTerminal},
+};
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{filter::Targets, layer::SubscriberExt, util::SubscriberInitExt};
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+/// Installs the subscriber for `day`, which should be the crate name, i.e.
+/// `env!("CARGO_PKG_NAME")`. Calling it more than once is harmless.
+pub fn init(day: &str) {
+    let level = std::env::var(ENV_VAR)
+        .map(|directives| level_for(day, &directives))
+        .unwrap_or(LevelFilter::OFF);
+
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .try_init();
+}
+
+/// Installs one subscriber for every day at once, for a runner that solves
+/// in-process and so has no `AOC_LOG` per binary. Each day's events are kept
+/// at the level `directives` select for that day, with the same syntax as
+/// `AOC_LOG`. Calling it more than once is harmless.
+pub fn init_all(directives: &str) {
+    let _ = tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .finish()
+        .with(targets(directives))
+        .try_init();
+}
+
+/// The directives as a filter on the days' crates, whose events have the
+/// crate name with underscores as their target, e.g. `day_05`.
+fn targets(directives: &str) -> Targets {
+    let days: BTreeSet<&str> = directives
+        .split(',')
+        .filter_map(|directive| directive.split_once('='))
+        .map(|(day, _)| day.trim())
+        .collect();
+
+    // no day is called "", so this is the level of the days not named
+    let default = level_for("", directives);
+
+    days.into_iter()
+        .fold(Targets::new().with_default(default), |targets, day| {
+            targets.with_target(day.replace('-', "_"), level_for(day, directives))
+        })
+}
+
+/// The level the directives select for `day`. A directive naming the day beats
+/// a bare level, and later directives override earlier ones of the same kind.
+/// Directives that do not parse are ignored.
+fn level_for(day: &str, directives: &str) -> LevelFilter {
+    let mut default = None;
+    let mut specific = None;
+
+    for directive in directives.split(',').map(str::trim) {
+        // `LevelFilter` parses the empty string as `ERROR`
+        if directive.is_empty() {
+            continue;
+        }
+
+        match directive.split_once('=') {
+            Some((target, level)) if target.trim() == day => {
+                specific = level.trim().parse().ok().or(specific);
+            }
+            Some(_) => {}
+            None => default = directive.parse().ok().or(default),
+        }
+    }
+
+    specific.or(default).unwrap_or(LevelFilter::OFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_most_specific_level() {
+        assert_eq!(level_for("day-05", ""), LevelFilter::OFF);
+        assert_eq!(level_for("day-05", "info"), LevelFilter::INFO);
+        assert_eq!(level_for("day-05", "warn,day-05=trace"), LevelFilter::TRACE);
+        assert_eq!(
+            level_for("day-05", "day-05=trace, warn"),
+            LevelFilter::TRACE
+        );
+        assert_eq!(level_for("day-04", "warn,day-05=trace"), LevelFilter::WARN);
+        assert_eq!(level_for("day-04", "day-05=trace"), LevelFilter::OFF);
+        assert_eq!(level_for("day-04", "loud,debug"), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn filters_every_day_at_once() {
+        use tracing::Level;
+
+        let filter = targets("warn,day-05=trace,day-08=off");
+
+        assert!(filter.would_enable("day_05::almanac", &Level::TRACE));
+        assert!(!filter.would_enable("day_08", &Level::ERROR));
+        assert!(filter.would_enable("day_04", &Level::WARN));
+        assert!(!filter.would_enable("day_04", &Level::INFO));
+        assert!(!targets("").would_enable("day_01", &Level::ERROR));
+    }
+}
@@ -0,0 +1,264 @@
+//! Number theory helpers that work on any primitive integer type and report
+//! overflow as `None` instead of wrapping or panicking.
+
+use num_traits::{PrimInt, Signed};
+
+/// The remainder of `a / b` with the sign of `a`, treating `MIN % -1` (which
+/// overflows for signed types) as the `0` it mathematically is.
+fn rem<T: PrimInt>(a: T, b: T) -> T {
+    match a.checked_div(&b) {
+        Some(quotient) => a - quotient * b,
+        None => T::zero(),
+    }
+}
+
+fn abs<T: PrimInt>(value: T) -> Option<T> {
+    if value < T::zero() {
+        T::zero().checked_sub(&value)
+    } else {
+        Some(value)
+    }
+}
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is `0`.
+///
+/// Returns `None` only when the result does not fit in `T`, which can only
+/// happen for `gcd(T::MIN, 0)` and `gcd(T::MIN, T::MIN)` on signed types.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+
+    while !b.is_zero() {
+        (a, b) = (b, rem(a, b));
+    }
+
+    abs(a)
+}
+
+/// Least common multiple, always non-negative, or `None` if it overflows `T`.
+/// The lcm of anything and `0` is `0`.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
+    }
+
+    // dividing first keeps the intermediate value no larger than the result
+    let divisor = gcd(a, b)?;
+    abs(a / divisor)?.checked_mul(&abs(b)?)
+}
+
+/// Sum of `values`, or `None` as soon as it overflows `T`.
+pub fn checked_sum<T: PrimInt>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::zero(), |total, value| total.checked_add(&value))
+}
+
+/// Product of `values`, or `None` as soon as it overflows `T`.
+pub fn checked_product<T: PrimInt>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::one(), |total, value| total.checked_mul(&value))
+}
+
+/// Solves `a * x + b * y = gcd(a, b)`, returning `(gcd, x, y)`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = old_r.checked_div(&r)?;
+
+        (old_r, r) = (r, old_r.checked_sub(&quotient.checked_mul(&r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(&quotient.checked_mul(&x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(&quotient.checked_mul(&y)?)?);
+    }
+
+    if old_r < T::zero() {
+        return Some((-old_r, -old_x, -old_y));
+    }
+
+    Some((old_r, old_x, old_y))
+}
+
+/// `value` reduced into `0..modulus`, for a positive `modulus`.
+fn normalise<T: PrimInt + Signed>(value: T, modulus: T) -> T {
+    let remainder = rem(value, modulus);
+
+    if remainder < T::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+/// `(a + b) % modulus` for `a` and `b` already in `0..modulus`, without overflow.
+fn add_mod<T: PrimInt>(a: T, b: T, modulus: T) -> T {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `(a * b) % modulus` for `a` and `b` already in `0..modulus`, without overflow.
+fn mul_mod<T: PrimInt>(a: T, b: T, modulus: T) -> T {
+    let (mut a, mut b) = (a, b);
+    let mut product = T::zero();
+
+    while !b.is_zero() {
+        if !(b & T::one()).is_zero() {
+            product = add_mod(product, a, modulus);
+        }
+
+        a = add_mod(a, a, modulus);
+        b = b >> 1;
+    }
+
+    product
+}
+
+/// The `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, if `a` and `modulus`
+/// are coprime and `modulus` is positive.
+pub fn mod_inverse<T: PrimInt + Signed>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::zero() {
+        return None;
+    }
+
+    let (divisor, x, _) = extended_gcd(normalise(a, modulus), modulus)?;
+
+    if !divisor.is_one() {
+        return None;
+    }
+
+    Some(normalise(x, modulus))
+}
+
+/// Generalised Chinese remainder theorem: finds the `x` satisfying every
+/// `x ≡ residue (mod modulus)` pair, where the moduli need not be coprime.
+///
+/// Returns `(x, lcm of the moduli)` with `x` in `0..lcm`, or `None` if the
+/// congruences contradict each other, a modulus is not positive, or the lcm
+/// overflows `T`. No congruences at all are satisfied by `(0, 1)`.
+pub fn crt<T: PrimInt + Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(x, modulus), &(residue, other)| {
+            if other <= T::zero() {
+                return None;
+            }
+
+            let residue = normalise(residue, other);
+            let divisor = gcd(modulus, other)?;
+            let difference = residue - normalise(x, other);
+
+            if !rem(difference, divisor).is_zero() {
+                return None;
+            }
+
+            // x + modulus * t ≡ residue (mod other), solved for t modulo other / divisor
+            let reduced = other / divisor;
+            let combined = lcm(modulus, other)?;
+            let inverse = mod_inverse(modulus / divisor, reduced)?;
+            let steps = mul_mod(normalise(difference / divisor, reduced), inverse, reduced);
+            let offset = mul_mod(normalise(modulus, combined), steps, combined);
+
+            Some((add_mod(normalise(x, combined), offset, combined), combined))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12_u32, 18), Some(6));
+        assert_eq!(gcd(-12_i32, 18), Some(6));
+        assert_eq!(gcd(0_u8, 0), Some(0));
+        assert_eq!(gcd(i64::MIN, -1), Some(1));
+        assert_eq!(gcd(i64::MIN, 0), None);
+        assert_eq!(lcm(4_usize, 6), Some(12));
+        assert_eq!(lcm(-4_i32, 6), Some(12));
+        assert_eq!(lcm(0_u64, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(1_u64 << 40, 1_u64 << 41), Some(1_u64 << 41));
+        assert_eq!(checked_sum([1_u8, 2, 3]), Some(6));
+        assert_eq!(checked_sum([200_u8, 100]), None);
+        assert_eq!(checked_product(Vec::<u8>::new()), Some(1));
+        assert_eq!(checked_product([16_u8, 16]), None);
+    }
+
+    #[test]
+    fn inverses_and_crt() {
+        assert_eq!(mod_inverse(3_i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3_i64, 11), Some(7));
+        assert_eq!(mod_inverse(6_i64, 9), None);
+        assert_eq!(crt(&[(2_i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3_i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1_i64, 4), (2, 6)]), None);
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+        assert_eq!(
+            crt(&[(1_i64, i64::MAX), (0, 2)]),
+            None,
+            "the lcm does not fit in i64"
+        );
+        assert_eq!(
+            crt(&[(i64::MAX - 1, i64::MAX), (0, 1)]),
+            Some((i64::MAX - 1, i64::MAX))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(a in any::<i64>(), b in any::<i64>()) {
+            if let Some(divisor) = gcd(a, b) {
+                prop_assert!(divisor >= 0);
+                if divisor != 0 {
+                    prop_assert_eq!(a % divisor, 0);
+                    prop_assert_eq!(b % divisor, 0);
+                    prop_assert_eq!(gcd(a / divisor, b / divisor), Some(1));
+                }
+            }
+        }
+
+        #[test]
+        fn lcm_matches_wide_arithmetic(a in any::<u32>(), b in any::<u32>()) {
+            let expected = if a == 0 || b == 0 {
+                0
+            } else {
+                a as u128 * b as u128 / gcd(a as u128, b as u128).unwrap()
+            };
+
+            prop_assert_eq!(lcm(a, b).map(u128::from), u32::try_from(expected).ok().map(u128::from));
+        }
+
+        #[test]
+        fn extended_gcd_satisfies_bezout(a in -1_000_000_000_i64..1_000_000_000, b in -1_000_000_000_i64..1_000_000_000) {
+            let (divisor, x, y) = extended_gcd(a, b).unwrap();
+
+            prop_assert_eq!(Some(divisor), gcd(a, b));
+            prop_assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, divisor as i128);
+        }
+
+        #[test]
+        fn crt_agrees_with_brute_force(congruences in prop::collection::vec((any::<i32>(), 1_i32..30), 0..4)) {
+            let congruences: Vec<(i64, i64)> = congruences
+                .into_iter()
+                .map(|(residue, modulus)| (residue as i64, modulus as i64))
+                .collect();
+            let modulus = congruences
+                .iter()
+                .try_fold(1, |acc, &(_, modulus)| lcm(acc, modulus))
+                .unwrap();
+            let brute_force = (0..modulus).find(|x| {
+                congruences
+                    .iter()
+                    .all(|&(residue, other)| (x - residue).rem_euclid(other) == 0)
+            });
+
+            prop_assert_eq!(crt(&congruences), brute_force.map(|x| (x, modulus)));
+        }
+    }
+}
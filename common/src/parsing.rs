@@ -0,0 +1,174 @@
+//! nom combinators for the input shapes that keep coming back: `Label N:`
+//! headers, whitespace separated numbers and blank line separated sections.
+//!
+//! Everything here reports failures as an [`ErrorTree`], so a parse that goes
+//! wrong explains what it expected and where, instead of a bare `ErrorKind`.
+
+use std::{fmt, str::FromStr};
+
+use nom::{
+    character::complete::{char, digit1, line_ending, multispace0, space0, space1},
+    combinator::{opt, recognize},
+    multi::separated_list1,
+    sequence::pair,
+    IResult, Parser,
+};
+use nom_supreme::{
+    error::ErrorTree,
+    final_parser::{final_parser, Location},
+    tag::complete::tag,
+    ParserExt,
+};
+
+pub type ParseResult<'a, T> = IResult<&'a str, T, ErrorTree<&'a str>>;
+
+/// A parse failure with line and column information relative to the parsed input.
+#[derive(Debug)]
+pub struct ParseError(pub ErrorTree<Location>);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over the whole of `input`, failing if anything but trailing
+/// whitespace is left over.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, ErrorTree<&'a str>>,
+) -> Result<T, ParseError> {
+    final_parser(parser.terminated(multispace0))(input).map_err(ParseError)
+}
+
+/// A decimal integer, optionally negative.
+pub fn number<'a, T>(input: &'a str) -> ParseResult<'a, T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    recognize(pair(opt(char('-')), digit1))
+        .parse_from_str()
+        .context("number")
+        .parse(input)
+}
+
+/// Numbers separated by spaces, e.g. ` 41 48  6 31`. Leading spaces are skipped
+/// and alignment padding between numbers is allowed.
+pub fn numbers<'a, T>(input: &'a str) -> ParseResult<'a, Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    separated_list1(space1, number)
+        .preceded_by(space0)
+        .context("space separated numbers")
+        .parse(input)
+}
+
+/// A `Label N:` header such as `Game 12:` or `Card   3:`, returning `N`.
+pub fn header<'a>(label: &'static str) -> impl FnMut(&'a str) -> ParseResult<'a, usize> {
+    move |input| {
+        tag(label)
+            .precedes(space1)
+            .precedes(number)
+            .terminated(char(':'))
+            .context(label)
+            .parse(input)
+    }
+}
+
+/// Parses `line` repeatedly, one per line.
+pub fn lines<'a, T>(
+    line: impl Parser<&'a str, T, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<T>> {
+    separated_list1(line_ending, line)
+}
+
+/// A block introduced by a `title` line, e.g. `seed-to-soil map:`, whose body
+/// is parsed by `body`. The blank lines separating it from the next section are
+/// consumed too, so sections can be parsed one after another.
+pub fn section<'a, T>(
+    title: &'static str,
+    body: impl Parser<&'a str, T, ErrorTree<&'a str>>,
+) -> impl FnMut(&'a str) -> ParseResult<'a, T> {
+    let mut parser = tag(title)
+        .terminated(pair(space0, line_ending))
+        .precedes(body)
+        .terminated(multispace0)
+        .context(title);
+
+    move |input| parser.parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_headers() {
+        assert_eq!(header("Game")("Game 12: 3 blue").unwrap(), (" 3 blue", 12));
+        assert_eq!(header("Card")("Card   3:  1 21").unwrap(), ("  1 21", 3));
+        assert!(header("Card")("Game 1:").is_err());
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(
+            numbers::<u32>(" 83 86  6 31 | 17").unwrap(),
+            (" | 17", vec![83, 86, 6, 31])
+        );
+        assert_eq!(numbers::<i32>("-1 0 -12").unwrap(), ("", vec![-1, 0, -12]));
+        assert!(numbers::<u32>("-1").is_err());
+    }
+
+    #[test]
+    fn parses_sections() {
+        let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+";
+        let (remaining, seeds) = tag("seeds:")
+            .precedes(numbers::<u64>)
+            .terminated(multispace0)
+            .parse(input)
+            .unwrap();
+        let (remaining, first) =
+            section("seed-to-soil map:", lines(numbers::<u64>))(remaining).unwrap();
+        let second = parse_all(
+            remaining,
+            section("soil-to-fertilizer map:", lines(numbers::<u64>)),
+        )
+        .unwrap();
+
+        assert_eq!(seeds, vec![79, 14]);
+        assert_eq!(first, vec![vec![50, 98, 2], vec![52, 50, 48]]);
+        assert_eq!(second, vec![vec![0, 15, 37]]);
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let message = parse_all("Game x: 3 blue", header("Game"))
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("\"Game\" at line 1, column 1"), "{message}");
+        assert!(message.contains("ascii digit at line 1, column 6"), "{message}");
+
+        let message = parse_all(
+            "a map:\n1 2\n3 x\n",
+            section("a map:", lines(numbers::<u8>)),
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(message.contains("line 3, column 3"), "{message}");
+    }
+}
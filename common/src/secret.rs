@@ -0,0 +1,196 @@
+//! Puzzle inputs sealed with ChaCha20-Poly1305, since the puzzle author asks
+//! that inputs not be shared. A sealed input sits next to where its plain text
+//! would be, as `input1.txt.enc`, and [`read`] opens it with the key from the
+//! `AOC_INPUT_KEY` environment variable or `.aoc/input.key`, both in hex.
+//!
+//! A plain text copy, e.g. one decrypted to edit, wins over the sealed one.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, KeyInit, Nonce};
+use rand::Rng;
+
+/// The environment variable holding the key, which wins over the key file.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// What a sealed file starts with, so that plain text is never mistaken for one.
+const MAGIC: &[u8] = b"aoc-sealed-1\n";
+const NONCE_LEN: usize = 12;
+
+/// The key every input is sealed with, shared by the team out of band.
+#[derive(Clone, PartialEq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Key {
+        Key(rand::rng().random())
+    }
+
+    pub fn from_hex(hex: &str) -> io::Result<Key> {
+        let hex = hex.trim();
+        let invalid = || invalid_data("the input key is not 64 hex digits".to_string());
+
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        }
+
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Where the key is kept when it is not in [`KEY_VAR`]: `.aoc/input.key` at
+    /// the top of the repository, which git ignores.
+    pub fn file() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(".aoc")
+            .join("input.key")
+    }
+
+    /// The key from [`KEY_VAR`], or else from [`Key::file`].
+    pub fn load() -> io::Result<Key> {
+        if let Ok(hex) = env::var(KEY_VAR) {
+            return Key::from_hex(&hex);
+        }
+
+        match fs::read_to_string(Key::file()) {
+            Ok(hex) => Key::from_hex(&hex),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "no input key: set {KEY_VAR} or save it in {}",
+                    Key::file().display()
+                ),
+            )),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+/// Encrypts `plain`, under a fresh nonce every time.
+pub fn seal(key: &Key, plain: &[u8]) -> Vec<u8> {
+    let nonce: [u8; NONCE_LEN] = rand::rng().random();
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let encrypted = cipher
+        .encrypt(Nonce::from_slice(&nonce), plain)
+        .expect("inputs are far smaller than ChaCha20-Poly1305 can seal");
+
+    [MAGIC, &nonce, &encrypted].concat()
+}
+
+/// Decrypts what [`seal`] made, failing if it was made with another key or
+/// changed since.
+pub fn open(key: &Key, sealed: &[u8]) -> io::Result<Vec<u8>> {
+    let Some(rest) = sealed.strip_prefix(MAGIC) else {
+        return Err(invalid_data("not a sealed input".to_string()));
+    };
+
+    if rest.len() < NONCE_LEN {
+        return Err(invalid_data("the sealed input is truncated".to_string()));
+    }
+
+    let (nonce, encrypted) = rest.split_at(NONCE_LEN);
+
+    ChaCha20Poly1305::new(&key.0.into())
+        .decrypt(Nonce::from_slice(nonce), encrypted)
+        .map_err(|_| invalid_data("wrong input key, or the sealed input is corrupt".to_string()))
+}
+
+/// Whether `bytes` are a sealed input rather than plain text.
+pub fn is_sealed(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Where the sealed copy of the plain text input at `path` lives.
+pub fn sealed_path(path: &Path) -> PathBuf {
+    let mut sealed = path.as_os_str().to_owned();
+    sealed.push(".enc");
+
+    PathBuf::from(sealed)
+}
+
+/// Whether there is an input at `path`, plain or sealed.
+pub fn exists(path: &Path) -> bool {
+    path.is_file() || sealed_path(path).is_file()
+}
+
+/// The input at `path`, or else its sealed copy decrypted.
+pub fn read(path: &Path) -> io::Result<String> {
+    let sealed = sealed_path(path);
+
+    if path.is_file() || !sealed.is_file() {
+        return fs::read_to_string(path);
+    }
+
+    let plain = open(&Key::load()?, &fs::read(&sealed)?)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", sealed.display())))?;
+
+    String::from_utf8(plain).map_err(|error| invalid_data(format!("{}: {error}", sealed.display())))
+}
+
+/// Reads a `partN` binary's input, the file `$file` names in its `src/bin`
+/// directory, with [`read`], as a `Result` ready to chain into the solver.
+/// Days name their files in their `INPUTS`, so `aoc` can find them too.
+#[macro_export]
+macro_rules! puzzle_input {
+    ($file:expr) => {
+        $crate::secret::read(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src/bin")
+                .join($file),
+        )
+        .map_err($crate::solve::SolveError::Read)
+    };
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_what_it_seals() {
+        let key = Key::generate();
+        let sealed = seal(&key, b"Time: 7\nDistance: 9\n");
+
+        assert!(is_sealed(&sealed));
+        assert_ne!(seal(&key, b"Time: 7\nDistance: 9\n"), sealed);
+        assert_eq!(open(&key, &sealed).unwrap(), b"Time: 7\nDistance: 9\n");
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap(), key);
+    }
+
+    #[test]
+    fn refuses_the_wrong_key_and_tampering() {
+        let key = Key::generate();
+        let mut sealed = seal(&key, b"32T3K 765\n");
+
+        assert!(open(&Key::generate(), &sealed).is_err());
+        assert!(open(&key, b"32T3K 765\n").is_err());
+
+        *sealed.last_mut().unwrap() ^= 1;
+        assert!(open(&key, &sealed).is_err());
+
+        assert!(Key::from_hex("not hex").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! The error every solver returns instead of panicking, so that malformed or
+//! hostile input ends in a message rather than a crash.
+
+use std::{fmt, io, process::ExitCode};
+
+use crate::{grid::GridError, parsing::ParseError, validate::Report};
+
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(ParseError),
+    Grid(GridError),
+    /// The input parsed, but breaks an assumption the solver relies on.
+    Invalid(String),
+    /// The input breaks assumptions the day checks before solving, see
+    /// [`crate::validate`].
+    Violations(Report),
+    /// The named quantity does not fit the solver's integer type.
+    Overflow(&'static str),
+    /// The runner gave up on the solver, see [`crate::cancel`].
+    Cancelled,
+    /// The input could not be read, decrypted or streamed, see
+    /// [`crate::secret`] and [`crate::input::for_each_line`].
+    Read(io::Error),
+}
+
+impl SolveError {
+    pub fn invalid(message: impl Into<String>) -> SolveError {
+        SolveError::Invalid(message.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "could not parse the input: {error}"),
+            SolveError::Grid(error) => write!(f, "could not parse the input: {error}"),
+            SolveError::Invalid(message) => write!(f, "unsupported input: {message}"),
+            SolveError::Violations(report) => {
+                let count = report.violations.len();
+                let plural = if count == 1 { "" } else { "s" };
+                write!(
+                    f,
+                    "unsupported input, it breaks {count} assumption{plural}:"
+                )?;
+
+                for line in report.to_string().lines() {
+                    write!(f, "\n  {line}")?;
+                }
+
+                Ok(())
+            }
+            SolveError::Overflow(quantity) => write!(f, "{quantity} overflows"),
+            SolveError::Cancelled => write!(f, "cancelled"),
+            SolveError::Read(error) => write!(f, "could not read the input: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl From<GridError> for SolveError {
+    fn from(error: GridError) -> Self {
+        SolveError::Grid(error)
+    }
+}
+
+/// Prints the answer on stdout, or the error on stderr with a failing exit code.
+pub fn report<T: fmt::Display>(answer: Result<T, SolveError>) -> ExitCode {
+    match answer {
+        Ok(answer) => {
+            println!("{answer}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
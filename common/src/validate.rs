@@ -0,0 +1,115 @@
+//! Checks of the assumptions a solver makes beyond what its parser enforces,
+//! such as every name a line refers to having a line of its own. Days run
+//! them before solving, so an input that breaks one is reported line by line
+//! instead of giving a wrong answer or a walk that never ends.
+//!
+//! The checks look at the lines as they are and skip any they cannot make
+//! sense of, leaving those for the parser to explain.
+
+use std::fmt;
+
+use crate::solve::SolveError;
+
+/// One assumption the input breaks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// The line breaking it, counted from 1, or `None` when it is down to the
+    /// input as a whole, like a node that should be there but is not.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+/// Every assumption an input breaks, in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub violations: Vec<Violation>,
+}
+
+impl Report {
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    /// Records that `line`, counted from 1, breaks an assumption.
+    pub fn line(&mut self, line: usize, message: impl Into<String>) {
+        self.violations.push(Violation {
+            line: Some(line),
+            message: message.into(),
+        });
+    }
+
+    /// Records an assumption the input as a whole breaks.
+    pub fn input(&mut self, message: impl Into<String>) {
+        self.violations.push(Violation {
+            line: None,
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// `Ok` if the input breaks nothing, otherwise the report as an error.
+    pub fn into_result(self) -> Result<(), SolveError> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(SolveError::Violations(self)),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            match violation.line {
+                Some(line) => write!(f, "line {line}: {}", violation.message)?,
+                None => write!(f, "{}", violation.message)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The lines of `input` with their numbers, counted from 1 as editors do.
+pub fn numbered(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_each_violation() {
+        let mut report = Report::new();
+        assert!(report.clone().into_result().is_ok());
+
+        report.line(3, "hand \"AAAA\" has 4 cards, not 5");
+        report.input("there is no node AAA");
+
+        assert_eq!(
+            report.to_string(),
+            "line 3: hand \"AAAA\" has 4 cards, not 5\nthere is no node AAA"
+        );
+        assert_eq!(
+            report.into_result().unwrap_err().to_string(),
+            "unsupported input, it breaks 2 assumptions:
+  line 3: hand \"AAAA\" has 4 cards, not 5
+  there is no node AAA"
+        );
+    }
+
+    #[test]
+    fn numbers_lines_from_one() {
+        assert_eq!(
+            numbered("a\nb").collect::<Vec<_>>(),
+            vec![(1, "a"), (2, "b")]
+        );
+    }
+}
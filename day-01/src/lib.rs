@@ -0,0 +1,142 @@
+//! Day 1: Trebuchet?! Recovering calibration values from lines of text.
+
+use std::io::BufRead;
+
+use aho_corasick::{AhoCorasick, Match};
+use common::{input::for_each_line, solve::SolveError};
+use tracing::{debug, info, trace};
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input2.txt"];
+
+/// Sums the calibration values made of each line's first and last digit.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    part1_reader(input.as_bytes())
+}
+
+/// Like [`part1`], but digits spelled out as words count too.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    part2_reader(input.as_bytes())
+}
+
+/// [`part1`] for an input streamed one line at a time.
+pub fn part1_reader(reader: impl BufRead) -> Result<usize, SolveError> {
+    calibration_total(reader, parse_number)
+}
+
+/// [`part2`] for an input streamed one line at a time.
+pub fn part2_reader(reader: impl BufRead) -> Result<usize, SolveError> {
+    calibration_total(reader, |line| {
+        let converted = convert_to_numbers(line);
+        let line_number = parse_number(&converted)?;
+
+        debug!(line, converted, line_number, "calibration line");
+
+        Ok(line_number)
+    })
+}
+
+fn calibration_total(
+    reader: impl BufRead,
+    mut value: impl FnMut(&str) -> Result<usize, SolveError>,
+) -> Result<usize, SolveError> {
+    let mut sum: usize = 0;
+    for_each_line(reader, |line| {
+        let line_number = value(line)?;
+
+        sum += line_number;
+        Ok(())
+    })?;
+
+    info!(sum, "calibration total");
+
+    Ok(sum)
+}
+
+/// Replaces the spelled out digits in `input` with numerals. Words that share
+/// letters, like `eightwo`, both turn into digits.
+pub fn convert_to_numbers(input: &str) -> String {
+    let mut converted = String::from(input);
+    let patterns = &[
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let replacements = &["1e", "2o", "3e", "4", "5e", "6", "7", "8t", "9e"];
+    let ac = AhoCorasick::new(patterns).unwrap();
+    let matches: Vec<Match> = ac.find_overlapping_iter(input).collect();
+
+    matches.iter().for_each(|_m| {
+        converted = ac.replace_all(&converted, replacements);
+    });
+
+    converted
+}
+
+/// The two digit number formed by the first and last digit of `line`.
+pub fn parse_number(line: &str) -> Result<usize, SolveError> {
+    let line_digits: Vec<usize> = line
+        .chars()
+        .filter_map(|char| char.to_digit(10))
+        .map(|digit| digit as usize)
+        .collect();
+
+    trace!(digits = ?line_digits);
+
+    let (Some(first), Some(last)) = (line_digits.first(), line_digits.last()) else {
+        return Err(SolveError::invalid(format!("{line:?} has no digits")));
+    };
+
+    Ok(first * 10 + last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART1_EXAMPLE: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const PART2_EXAMPLE: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(PART1_EXAMPLE).unwrap(), 142);
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(PART2_EXAMPLE).unwrap(), 281);
+    }
+
+    #[test]
+    fn hmm() {
+        let result = parse_number(&convert_to_numbers("zoneight234"));
+        let result2 = parse_number(&convert_to_numbers("4nineeightseven2"));
+        assert_eq!(result.unwrap(), 14);
+        assert_eq!(result2.unwrap(), 42);
+    }
+
+    #[test]
+    fn streams_a_large_input() {
+        // a hundred thousand lines, made as they are read
+        let copies = 25_000;
+        let part1_lines = PART1_EXAMPLE.lines().map(String::from).cycle();
+        let part1_input = common::input::reader(part1_lines.take(4 * copies));
+
+        assert_eq!(part1_reader(part1_input).unwrap(), 142 * copies);
+
+        // spelled out digits are slow to find, so fewer lines for part 2
+        let copies = 1_000;
+        let part2_lines = PART2_EXAMPLE.lines().map(String::from).cycle();
+        let part2_input = common::input::reader(part2_lines.take(7 * copies));
+
+        assert_eq!(part2_reader(part2_input).unwrap(), 281 * copies);
+    }
+}
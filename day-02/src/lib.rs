@@ -0,0 +1,206 @@
+//! Day 2: Cube Conundrum. Games of cubes drawn from a bag.
+
+use std::io::BufRead;
+
+use common::{
+    input::for_each_line,
+    math::checked_product,
+    parsing::{header, number, parse_all, ParseResult},
+    solve::SolveError,
+};
+use nom::{
+    character::complete::{alpha1, char, space0, space1},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+use tracing::info;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+/// Both parts play the same games, so they share an input.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input1.txt"];
+
+/// Sums the ids of the games that were possible with 12 red, 13 green and 14
+/// blue cubes in the bag.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    part1_reader(input.as_bytes())
+}
+
+/// Sums the power of the fewest cubes that make each game possible.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    part2_reader(input.as_bytes())
+}
+
+/// [`part1`] for an input streamed one game at a time.
+pub fn part1_reader(reader: impl BufRead) -> Result<usize, SolveError> {
+    sum_over_games(reader, "the sum of game ids", |game| {
+        let possible = game.rounds.iter().all(Round::is_valid);
+
+        Some(if possible { game.id } else { 0 })
+    })
+}
+
+/// [`part2`] for an input streamed one game at a time.
+pub fn part2_reader(reader: impl BufRead) -> Result<usize, SolveError> {
+    let game_power = sum_over_games(reader, "the total game power", |game| {
+        game.minimum_round().power()
+    })?;
+
+    info!(game_power);
+
+    Ok(game_power)
+}
+
+/// Sums `value` over every game. `quantity` names the sum for the error when
+/// a value or the sum overflows.
+fn sum_over_games(
+    reader: impl BufRead,
+    quantity: &'static str,
+    value: impl Fn(&Game) -> Option<usize>,
+) -> Result<usize, SolveError> {
+    let mut sum: usize = 0;
+
+    for_each_line(reader, |line| {
+        let game = parse_all(line, parse_game)?;
+
+        sum = value(&game)
+            .and_then(|value| sum.checked_add(value))
+            .ok_or(SolveError::Overflow(quantity))?;
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: usize,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    /// The fewest cubes of each color that every round of the game fits in.
+    pub fn minimum_round(&self) -> Round {
+        let mut minimum_round = Round {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        for round in self.rounds.iter() {
+            if round.red > minimum_round.red {
+                minimum_round.red = round.red;
+            }
+
+            if round.green > minimum_round.green {
+                minimum_round.green = round.green;
+            }
+
+            if round.blue > minimum_round.blue {
+                minimum_round.blue = round.blue;
+            }
+        }
+
+        minimum_round
+    }
+}
+
+#[derive(Debug)]
+pub struct Round {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+
+impl Round {
+    /// Whether the round could be drawn from a bag of 12 red, 13 green and 14
+    /// blue cubes.
+    pub fn is_valid(&self) -> bool {
+        self.red <= 12 && self.green <= 13 && self.blue <= 14
+    }
+
+    /// The product of the three counts, or `None` if it overflows.
+    pub fn power(&self) -> Option<usize> {
+        checked_product([self.red, self.blue, self.green])
+    }
+}
+
+#[derive(Debug)]
+struct ColorCount {
+    number: usize,
+    color: String,
+}
+
+/// One line of the input, e.g. `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+pub fn parse_game(input: &str) -> ParseResult<'_, Game> {
+    let (input, id) = header("Game")(input)?;
+    let (input, rounds) = separated_list1(char(';'), parse_round)(input)?;
+
+    Ok((input, Game { id, rounds }))
+}
+
+fn parse_round(input: &str) -> ParseResult<'_, Round> {
+    let (input, rolls) = parse_rolls(input)?;
+    let mut round = Round {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+
+    for roll in rolls {
+        match roll.color.as_str() {
+            "red" => round.red = roll.number,
+            "green" => round.green = roll.number,
+            "blue" => round.blue = roll.number,
+            _ => {}
+        }
+    }
+
+    Ok((input, round))
+}
+
+fn parse_color_count(input: &str) -> ParseResult<'_, ColorCount> {
+    let (input, (number, color)) = separated_pair(number, space1, alpha1)(input)?;
+
+    Ok((
+        input,
+        ColorCount {
+            number,
+            color: color.to_string(),
+        },
+    ))
+}
+
+fn parse_rolls(input: &str) -> ParseResult<'_, Vec<ColorCount>> {
+    preceded(
+        space0,
+        separated_list1(tuple((char(','), space0)), parse_color_count),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 8);
+        assert_eq!(part2(EXAMPLE).unwrap(), 2286);
+    }
+
+    #[test]
+    fn streams_a_large_input() {
+        // a hundred thousand games, made as they are read
+        let copies = 20_000;
+        let lines = EXAMPLE.lines().map(String::from).cycle();
+        let reader = || common::input::reader(lines.clone().take(5 * copies));
+
+        assert_eq!(part1_reader(reader()).unwrap(), 8 * copies);
+        assert_eq!(part2_reader(reader()).unwrap(), 2286 * copies);
+    }
+}
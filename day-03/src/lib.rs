@@ -0,0 +1,123 @@
+//! Day 3: Gear Ratios. Numbers and symbols in an engine schematic.
+
+use std::collections::HashMap;
+
+use common::{
+    grid::{Grid, Point, Run},
+    input::normalise,
+    math::{checked_product, checked_sum},
+    solve::SolveError,
+};
+use tracing::info;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input2.txt"];
+
+/// Sums the numbers next to a symbol.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let schematic = Schematic::parse(&normalise(input))?;
+    let sum = checked_sum(schematic.part_numbers()?)
+        .ok_or(SolveError::Overflow("the sum of part numbers"))?;
+
+    info!(sum, "sum of part numbers");
+
+    Ok(sum)
+}
+
+/// Sums the products of the two numbers next to each gear that has exactly two.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let schematic = Schematic::parse(&normalise(input))?;
+
+    let sum = schematic
+        .gears()?
+        .values()
+        .filter(|adjacent_numbers| adjacent_numbers.len() == 2)
+        .map(|adjacent_numbers| checked_product(adjacent_numbers.iter().copied()))
+        .collect::<Option<Vec<usize>>>()
+        .and_then(checked_sum)
+        .ok_or(SolveError::Overflow("the sum of gear ratios"))?;
+
+    info!(sum, "sum of gear ratios");
+
+    Ok(sum)
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    grid: Grid<char>,
+}
+
+impl Schematic {
+    pub fn parse(input: &str) -> Result<Schematic, SolveError> {
+        Ok(Schematic {
+            grid: Grid::from_chars(input)?,
+        })
+    }
+
+    /// The numbers with a symbol in one of the eight cells around them.
+    pub fn part_numbers(&self) -> Result<Vec<usize>, SolveError> {
+        self.grid
+            .runs(char::is_ascii_digit)
+            .iter()
+            .filter(|number| {
+                self.grid
+                    .run_neighbours(number)
+                    .any(|point| is_symbol(self.grid[point]))
+            })
+            .map(|number| self.part_number(number))
+            .collect()
+    }
+
+    /// Every `*` that touches a number, with the numbers it touches.
+    pub fn gears(&self) -> Result<HashMap<Point, Vec<usize>>, SolveError> {
+        let mut gears: HashMap<Point, Vec<usize>> = HashMap::new();
+
+        for number in self.grid.runs(char::is_ascii_digit) {
+            let value = self.part_number(&number)?;
+
+            self.grid
+                .run_neighbours(&number)
+                .filter(|point| self.grid[*point] == '*')
+                .for_each(|gear| gears.entry(gear).or_default().push(value));
+        }
+
+        Ok(gears)
+    }
+
+    fn part_number(&self, number: &Run) -> Result<usize, SolveError> {
+        let digits: String = number.cells().map(|point| self.grid[point]).collect();
+
+        digits.parse().map_err(|_| {
+            SolveError::invalid(format!(
+                "part number {digits} on line {} is too large",
+                number.row + 1
+            ))
+        })
+    }
+}
+
+fn is_symbol(cell: char) -> bool {
+    !cell.is_alphanumeric() && cell != '.'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 4361);
+        assert_eq!(part2(EXAMPLE).unwrap(), 467835);
+    }
+}
@@ -0,0 +1,298 @@
+//! Day 4: Scratchcards. Cards that win points, or copies of the cards below.
+
+use std::{collections::VecDeque, io::BufRead};
+
+use common::{
+    input::{for_each_line, normalise},
+    parsing::{header, numbers, parse_all, ParseResult},
+    solve::SolveError,
+    validate::{numbered, Report},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    sequence::{separated_pair, tuple},
+};
+use tracing::{debug, info, trace};
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input2.txt"];
+
+/// Sums the points of every card: one for the first match, doubled for each
+/// match after it.
+pub fn part1(input: &str) -> Result<u32, SolveError> {
+    validate(input, 1).into_result()?;
+    part1_reader(input.as_bytes())
+}
+
+/// Counts the cards once every card has won copies of the cards below it, one
+/// per match.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    validate(input, 2).into_result()?;
+    part2_reader(input.as_bytes())
+}
+
+/// [`part1`] for an input streamed one card at a time.
+pub fn part1_reader(reader: impl BufRead) -> Result<u32, SolveError> {
+    let mut score: u32 = 0;
+
+    for_each_line(reader, |line| {
+        let card = parse_all(line, parse_card)?;
+
+        trace!(?card);
+
+        let winning_card_nums = card.matches();
+
+        debug!(card = card.id, winning = ?winning_card_nums, "matched numbers");
+
+        let points = match winning_card_nums.len() {
+            0 => Some(0),
+            matches => 2_u32.checked_pow(matches as u32 - 1),
+        };
+
+        score = points
+            .and_then(|points| score.checked_add(points))
+            .ok_or(SolveError::Overflow("the score"))?;
+        Ok(())
+    })?;
+
+    info!(score);
+
+    Ok(score)
+}
+
+/// [`part2`] for an input streamed one card at a time. Only the copies won by
+/// the next few cards are kept, so the ids must count up one at a time.
+pub fn part2_reader(reader: impl BufRead) -> Result<usize, SolveError> {
+    // the extra copies of the cards still to come, the next card's first
+    let mut won: VecDeque<usize> = VecDeque::new();
+    let mut previous_id: Option<usize> = None;
+    let mut total: usize = 0;
+
+    for_each_line(reader, |line| {
+        let card = parse_all(line, parse_card)?;
+
+        if let Some(previous_id) = previous_id {
+            if previous_id.checked_add(1) != Some(card.id) {
+                return Err(SolveError::invalid(format!(
+                    "card {} follows card {previous_id}",
+                    card.id
+                )));
+            }
+        }
+        previous_id = Some(card.id);
+
+        let copies = won
+            .pop_front()
+            .unwrap_or(0)
+            .checked_add(1)
+            .ok_or(SolveError::Overflow("the number of card copies"))?;
+        total = total
+            .checked_add(copies)
+            .ok_or(SolveError::Overflow("the number of cards"))?;
+
+        let matches = card.matches().len();
+        if won.len() < matches {
+            won.resize(matches, 0);
+        }
+
+        for extra in won.iter_mut().take(matches) {
+            *extra = extra
+                .checked_add(copies)
+                .ok_or(SolveError::Overflow("the number of card copies"))?;
+        }
+
+        debug!(card = card.id, copies, "copies of the card");
+
+        Ok(())
+    })?;
+
+    Ok(total)
+}
+
+/// What the solvers take for granted: no card lists a number twice, or a
+/// match would count twice, and for `part` 2 the ids count up one at a time,
+/// so that the `n` cards after a card with `n` matches are the ones it wins.
+pub fn validate(input: &str, part: u8) -> Report {
+    let input = normalise(input);
+    let mut report = Report::new();
+    let mut previous_id: Option<usize> = None;
+
+    for (line, text) in numbered(&input) {
+        let Ok(card) = parse_all(text, parse_card) else {
+            continue;
+        };
+
+        for (side, numbers) in [
+            ("winning numbers", &card.winning_numbers),
+            ("numbers", &card.card_numbers),
+        ] {
+            let mut seen = Vec::new();
+
+            for number in numbers {
+                if seen.contains(number) {
+                    report.line(line, format!("{number} is twice among the card's {side}"));
+                }
+                seen.push(*number);
+            }
+        }
+
+        if let (2, Some(previous_id)) = (part, previous_id) {
+            let expected = previous_id.checked_add(1);
+
+            if expected != Some(card.id) {
+                let message = match expected {
+                    Some(expected) => format!(
+                        "card {} follows card {previous_id} instead of card {expected}",
+                        card.id
+                    ),
+                    None => format!("card {} follows card {previous_id}", card.id),
+                };
+                report.line(line, message);
+            }
+        }
+        previous_id = Some(card.id);
+    }
+
+    report
+}
+
+#[derive(Debug)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: Vec<u32>,
+    pub card_numbers: Vec<u32>,
+}
+
+impl Card {
+    /// The card's numbers that are also winning numbers.
+    pub fn matches(&self) -> Vec<u32> {
+        self.card_numbers
+            .iter()
+            .copied()
+            .filter(|card_num| self.winning_numbers.contains(card_num))
+            .collect()
+    }
+}
+
+/// One line of the input, e.g. `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+pub fn parse_card(input: &str) -> ParseResult<'_, Card> {
+    let (remaining, id) = header("Card")(input)?;
+    let (remaining, (winning_numbers, card_numbers)) =
+        separated_pair(numbers, tuple((space1, tag("|"))), numbers)(remaining)?;
+
+    Ok((
+        remaining,
+        Card {
+            id,
+            winning_numbers,
+            card_numbers,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use common::{differential, parsing::lines};
+
+    /// Keeps the number of copies of every card by id.
+    fn copies_by_id(input: &str) -> Result<usize, SolveError> {
+        let parsed_cards = parse_all(input, lines(parse_card))?;
+        let mut cards: BTreeMap<usize, usize> =
+            parsed_cards.iter().map(|card| (card.id, 1)).collect();
+
+        for card in parsed_cards {
+            let times_to_process = cards[&card.id];
+
+            for card_num in card.id + 1..=card.id + card.matches().len() {
+                if let Some(copies) = cards.get_mut(&card_num) {
+                    *copies += times_to_process;
+                }
+            }
+        }
+
+        Ok(cards.values().sum())
+    }
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 13);
+        assert_eq!(part2(EXAMPLE).unwrap(), 30);
+    }
+
+    #[test]
+    fn why() {
+        let (_, test) = parse_card("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        let (_, test2) = parse_card("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap();
+        assert_eq!(test.card_numbers, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+        assert_eq!(test.winning_numbers, vec![41, 48, 83, 86, 17]);
+        assert_eq!(test2.winning_numbers, vec![13, 32, 20, 16, 61]);
+        assert_eq!(test2.card_numbers, vec![61, 30, 68, 82, 17, 32, 24, 19]);
+    }
+
+    #[test]
+    fn streams_a_large_input() {
+        // every other card wins a copy of the one after it
+        let count = 100_000;
+        let cards = (1..=count).map(|id| {
+            let number = if id % 2 == 0 { 1 } else { 3 };
+
+            format!("Card {id}: 1 2 | {number} 4")
+        });
+        let reader = || common::input::reader(cards.clone());
+
+        assert_eq!(part1_reader(reader()).unwrap(), count / 2);
+        assert_eq!(part2_reader(reader()).unwrap(), count as usize * 3 / 2 - 1);
+    }
+
+    #[test]
+    fn needs_consecutive_ids_to_stream() {
+        let result = part2_reader("Card 1: 1 | 1\nCard 3: 1 | 2\n".as_bytes());
+
+        assert!(matches!(result, Err(SolveError::Invalid(_))));
+    }
+
+    #[test]
+    fn reports_broken_assumptions() {
+        let input = "Card 1: 1 2 | 1 1\nCard 3: 1 | 2\nCard 4: 5 | 6\n";
+
+        assert_eq!(
+            validate(input, 1).to_string(),
+            "line 1: 1 is twice among the card's numbers"
+        );
+        assert_eq!(
+            validate(input, 2).to_string(),
+            "line 1: 1 is twice among the card's numbers
+line 2: card 3 follows card 1 instead of card 2"
+        );
+        assert!(matches!(part2(input), Err(SolveError::Violations(_))));
+        assert!(validate(EXAMPLE, 2).is_empty());
+    }
+
+    #[test]
+    fn reports_a_card_after_the_last_id() {
+        let input = "Card 18446744073709551615: 1 2 | 3 4\nCard 5: 1 2 | 3 4\n";
+
+        assert_eq!(
+            validate(input, 2).to_string(),
+            "line 2: card 5 follows card 18446744073709551615"
+        );
+    }
+
+    #[test]
+    fn agrees_with_copies_by_id() {
+        if let Err(mismatch) = differential::check(4, 1..=12, copies_by_id, part2) {
+            panic!("{mismatch}");
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Day 5: If You Give A Seed A Fertilizer. An almanac of maps from seeds to
+//! soil, fertilizer and so on down to locations.
+
+use std::ops::Range;
+
+use common::{
+    context::Context,
+    input::normalise,
+    interval::{IntervalSet, RangeMap},
+    parsing::{lines, number, numbers, parse_all, section, ParseResult},
+    solve::SolveError,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{multispace0, space1},
+    combinator::map_opt,
+    sequence::{preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input2.txt"];
+
+/// The lowest location of any of the seeds.
+pub fn part1(input: &str) -> Result<u64, SolveError> {
+    let almanac = parse_all(&normalise(input), parse)?;
+    let locations = almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.seed_to_location(*seed))
+        .collect::<Option<Vec<u64>>>()
+        .ok_or(SolveError::Overflow("a seed's location"))?;
+
+    locations
+        .into_iter()
+        .min()
+        .ok_or(SolveError::invalid("there are no seeds"))
+}
+
+/// The lowest location of any seed, now that the seeds line lists ranges as
+/// pairs of start and length.
+pub fn part2(input: &str) -> Result<u64, SolveError> {
+    part2_with(input, &Context::new())
+}
+
+/// [`part2`], reporting each map the seed ranges pass through and giving up
+/// between maps once `context` is cancelled.
+pub fn part2_with(input: &str, context: &Context) -> Result<u64, SolveError> {
+    let almanac = parse_all(&normalise(input), parse)?;
+    let locations = almanac.seeds_to_locations(&almanac.seed_ranges()?, context)?;
+
+    locations
+        .min()
+        .ok_or(SolveError::invalid("there are no seeds"))
+}
+
+pub fn parse(input: &str) -> ParseResult<'_, Almanac> {
+    let (remaining, seeds) = parse_seeds(input)?;
+    let (remaining, seed_to_soil_map) = parse_map(remaining, "seed-to-soil map:")?;
+    let (remaining, soil_to_fertilizer_map) = parse_map(remaining, "soil-to-fertilizer map:")?;
+    let (remaining, fertilizer_to_water_map) = parse_map(remaining, "fertilizer-to-water map:")?;
+    let (remaining, water_to_light_map) = parse_map(remaining, "water-to-light map:")?;
+    let (remaining, light_to_temperature_map) = parse_map(remaining, "light-to-temperature map:")?;
+    let (remaining, temperature_to_humidity_map) =
+        parse_map(remaining, "temperature-to-humidity map:")?;
+    let (remaining, humidity_to_location_map) = parse_map(remaining, "humidity-to-location map:")?;
+
+    Ok((
+        remaining,
+        Almanac {
+            seeds,
+            seed_to_soil_map,
+            soil_to_fertilizer_map,
+            fertilizer_to_water_map,
+            water_to_light_map,
+            light_to_temperature_map,
+            temperature_to_humidity_map,
+            humidity_to_location_map,
+        },
+    ))
+}
+
+fn parse_seeds(input: &str) -> ParseResult<'_, Vec<u64>> {
+    terminated(preceded(tag("seeds:"), numbers), multispace0)(input)
+}
+
+fn parse_map<'a>(input: &'a str, title: &'static str) -> ParseResult<'a, RangeMap<u64>> {
+    map_opt(section(title, lines(parse_range)), RangeMap::from_entries)(input)
+}
+
+/// One line of a map, `destination source length`, as the source range and
+/// the start of the destination range.
+pub fn parse_range(input: &str) -> ParseResult<'_, (Range<u64>, u64)> {
+    map_opt(
+        tuple((number::<u64>, space1, number::<u64>, space1, number::<u64>)),
+        |(destination_range_start, _, source_range_start, _, range_length)| {
+            // the ends of both ranges have to fit in a u64
+            destination_range_start.checked_add(range_length)?;
+
+            Some((
+                source_range_start..source_range_start.checked_add(range_length)?,
+                destination_range_start,
+            ))
+        },
+    )
+    .context("range that fits in u64")
+    .parse(input)
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub seed_to_soil_map: RangeMap<u64>,
+    pub soil_to_fertilizer_map: RangeMap<u64>,
+    pub fertilizer_to_water_map: RangeMap<u64>,
+    pub water_to_light_map: RangeMap<u64>,
+    pub light_to_temperature_map: RangeMap<u64>,
+    pub temperature_to_humidity_map: RangeMap<u64>,
+    pub humidity_to_location_map: RangeMap<u64>,
+}
+
+impl Almanac {
+    /// The seeds read as pairs of range start and length.
+    pub fn seed_ranges(&self) -> Result<IntervalSet<u64>, SolveError> {
+        let pairs = self.seeds.chunks_exact(2);
+
+        if !pairs.remainder().is_empty() {
+            return Err(SolveError::invalid("the seeds do not pair up into ranges"));
+        }
+
+        pairs
+            .map(|pair| Some(pair[0]..pair[0].checked_add(pair[1])?))
+            .collect::<Option<IntervalSet<u64>>>()
+            .ok_or(SolveError::Overflow("a seed range"))
+    }
+
+    /// The maps in the order a seed goes through them.
+    pub fn maps(&self) -> [&RangeMap<u64>; 7] {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temperature_to_humidity_map,
+            &self.humidity_to_location_map,
+        ]
+    }
+
+    /// The seed's location, or `None` if a map sends it past `u64::MAX`.
+    pub fn seed_to_location(&self, seed: u64) -> Option<u64> {
+        let soil = self.seed_to_soil_map.get(seed)?;
+        let fertilizer = self.soil_to_fertilizer_map.get(soil)?;
+        let water = self.fertilizer_to_water_map.get(fertilizer)?;
+        let light = self.water_to_light_map.get(water)?;
+        let temp = self.light_to_temperature_map.get(light)?;
+        let humidity = self.temperature_to_humidity_map.get(temp)?;
+
+        self.humidity_to_location_map.get(humidity)
+    }
+
+    /// Sends whole ranges of seeds through every map at once, so the number of
+    /// seeds never matters, only how many pieces the maps cut them into.
+    pub fn seeds_to_locations(
+        &self,
+        seeds: &IntervalSet<u64>,
+        context: &Context,
+    ) -> Result<IntervalSet<u64>, SolveError> {
+        let maps = self.maps();
+        context.progress().add_total(maps.len() as u64);
+
+        let mut ranges = seeds.clone();
+        for map in maps {
+            context.check()?;
+            ranges = map
+                .map(&ranges)
+                .ok_or(SolveError::Overflow("a mapped seed range"))?;
+            context.progress().advance(1);
+        }
+
+        Ok(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+
+";
+
+    /// Sends every seed of every range through the maps on its own.
+    fn per_seed(input: &str) -> Result<u64, SolveError> {
+        let almanac = parse_all(input, parse)?;
+
+        almanac
+            .seed_ranges()?
+            .ranges()
+            .flatten()
+            .map(|seed| almanac.seed_to_location(seed).unwrap())
+            .min()
+            .ok_or(SolveError::invalid("there are no seeds"))
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 35);
+        assert_eq!(part2(EXAMPLE).unwrap(), 46);
+    }
+
+    #[test]
+    fn reports_each_map() {
+        let context = Context::new();
+
+        assert_eq!(part2_with(EXAMPLE, &context).unwrap(), 46);
+        assert_eq!(context.progress().counts(), (7, 7));
+
+        context.cancel();
+        assert!(matches!(
+            part2_with(EXAMPLE, &context),
+            Err(SolveError::Cancelled)
+        ));
+    }
+
+    #[test]
+    fn test_range() {
+        let (_, result) = parse_range("50 98 2\n").unwrap();
+
+        assert_eq!(result, (98..100, 50));
+    }
+
+    #[test]
+    fn agrees_with_mapping_each_seed() {
+        if let Err(mismatch) = differential::check(5, 1..=8, per_seed, part2) {
+            panic!("{mismatch}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Day 6: Wait For It. Toy boat races won by holding the button just long enough.
+
+use common::{
+    input::normalise,
+    math::checked_product,
+    parsing::{numbers, parse_all, ParseResult},
+    solve::SolveError,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, multispace0},
+    sequence::{delimited, preceded, terminated},
+    Parser,
+};
+use nom_supreme::ParserExt;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input1.txt", "input2.txt"];
+
+/// Multiplies together the number of ways to win each race.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    let races = parse_all(&normalise(input), parse_races)?;
+
+    checked_product(races.iter().map(Race::ways_to_win))
+        .ok_or(SolveError::Overflow("the product of the ways to win"))
+}
+
+/// The number of ways to win the single race written with bad kerning.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    let race = parse_all(&normalise(input), parse_race)?
+        .ok_or(SolveError::Overflow("the kerned time or distance"))?;
+
+    Ok(race.ways_to_win())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    /// The distance to beat.
+    pub record: u64,
+}
+
+impl Race {
+    /// Counts the hold times that beat the record distance. The distance
+    /// travelled, `hold * (time - hold)`, rises until half the race time and
+    /// falls symmetrically after it, so finding the shortest winning hold is
+    /// enough.
+    pub fn ways_to_win(&self) -> usize {
+        let Race { time, record } = *self;
+        let beats_record = |hold: u64| hold as u128 * (time - hold) as u128 > record as u128;
+        let half = time / 2;
+
+        if !beats_record(half) {
+            return 0;
+        }
+
+        // holding for 0 never wins and holding for `half` does
+        let (mut losing, mut winning) = (0, half);
+
+        while winning - losing > 1 {
+            let middle = losing + (winning - losing) / 2;
+
+            if beats_record(middle) {
+                winning = middle;
+            } else {
+                losing = middle;
+            }
+        }
+
+        (time - 2 * winning + 1) as usize
+    }
+}
+
+/// The `Time:` and `Distance:` lines read column by column, one race each.
+pub fn parse_races(input: &str) -> ParseResult<'_, Vec<Race>> {
+    let (remaining, times) = delimited(tag("Time:"), numbers::<u64>, line_ending)(input)?;
+
+    numbers::<u64>
+        .verify(|distances: &Vec<u64>| distances.len() == times.len())
+        .context("a distance for every race time")
+        .preceded_by(tag("Distance:"))
+        .terminated(multispace0)
+        .map(|distances| {
+            times
+                .iter()
+                .zip(distances)
+                .map(|(&time, record)| Race { time, record })
+                .collect()
+        })
+        .parse(remaining)
+}
+
+/// The `Time:` and `Distance:` lines read as one race, ignoring the spaces,
+/// or `None` if either number is too long for a `u64`. A few more races than
+/// usual make a well formed input whose race does not fit.
+pub fn parse_race(input: &str) -> ParseResult<'_, Option<Race>> {
+    let (remaining, time) = delimited(tag("Time:"), kerned_number, line_ending)(input)?;
+    let (remaining, record) =
+        terminated(preceded(tag("Distance:"), kerned_number), multispace0)(remaining)?;
+
+    Ok((
+        remaining,
+        time.zip(record).map(|(time, record)| Race { time, record }),
+    ))
+}
+
+/// The digits of a line read as one number, ignoring the spaces between them.
+fn kerned_number(input: &str) -> ParseResult<'_, Option<u64>> {
+    numbers::<String>
+        .map(|digits| digits.join("").parse::<u64>().ok())
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    /// Tries every hold time.
+    fn brute_force(race: Race) -> usize {
+        (0..=race.time)
+            .filter(|hold| hold * (race.time - hold) > race.record)
+            .count()
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 288);
+        assert_eq!(part2(EXAMPLE).unwrap(), 71503);
+    }
+
+    #[test]
+    fn reports_a_kerned_race_too_long_for_u64() {
+        let input = "Time: 1234567890 1234567890 12\nDistance: 1 2 3\n";
+
+        assert!(matches!(part2(input), Err(SolveError::Overflow(_))));
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let every_race = |input: &str| {
+            let races = parse_all(input, parse_races)?;
+
+            Ok(races.into_iter().map(brute_force).product::<usize>())
+        };
+        let kerned_race = |input: &str| {
+            let race = parse_all(input, parse_race)?.ok_or(SolveError::Overflow("the race"))?;
+
+            Ok(brute_force(race))
+        };
+
+        if let Err(mismatch) = differential::check(6, 1..=6, every_race, part1) {
+            panic!("{mismatch}");
+        }
+
+        // the joined race time grows by two digits with each race
+        if let Err(mismatch) = differential::check(6, 1..=2, kerned_race, part2) {
+            panic!("{mismatch}");
+        }
+    }
+}
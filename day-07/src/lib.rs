@@ -0,0 +1,319 @@
+//! Day 7: Camel Cards. Ranking poker-like hands to total up their bets.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use common::{
+    input::normalise,
+    math::checked_sum,
+    parsing::{lines, number, parse_all, ParseResult},
+    solve::SolveError,
+    validate::{numbered, Report},
+};
+use nom::{
+    bytes::complete::take_while_m_n, character::complete::space1, sequence::separated_pair, Parser,
+};
+use nom_supreme::ParserExt;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input.txt", "input.txt"];
+
+/// The total winnings with `J` read as a jack.
+pub fn part1(input: &str) -> Result<u64, SolveError> {
+    let input = normalise(input);
+    validate_normalised(&input).into_result()?;
+    total_winnings(&input, Rules::Jacks)
+}
+
+/// The total winnings with `J` read as a joker.
+pub fn part2(input: &str) -> Result<u64, SolveError> {
+    let input = normalise(input);
+    validate_normalised(&input).into_result()?;
+    total_winnings(&input, Rules::Jokers)
+}
+
+/// What both parts take for granted: every hand is five known cards, and no
+/// hand is dealt twice, since equal hands would rank in whatever order the
+/// sort leaves them and their bets could be multiplied the wrong way round.
+pub fn validate(input: &str) -> Report {
+    validate_normalised(&normalise(input))
+}
+
+/// [`validate`] for an input that is already normalised, so that solving
+/// does not normalise it twice.
+fn validate_normalised(input: &str) -> Report {
+    let mut report = Report::new();
+    let mut dealt: HashMap<&str, usize> = HashMap::new();
+
+    for (line, text) in numbered(input) {
+        let Some(hand) = text.split_whitespace().next() else {
+            continue;
+        };
+
+        let cards = hand.chars().count();
+        if cards != 5 {
+            report.line(line, format!("hand {hand:?} has {cards} cards, not 5"));
+        }
+
+        if let Some(card) = hand.chars().find(|&card| !is_card(card)) {
+            report.line(line, format!("{card:?} in hand {hand:?} is not a card"));
+        }
+
+        match dealt.get(hand) {
+            Some(first) => report.line(
+                line,
+                format!("hand {hand:?} was dealt on line {first} already"),
+            ),
+            None => {
+                dealt.insert(hand, line);
+            }
+        }
+    }
+
+    report
+}
+
+/// Sums each hand's bet times its rank, the weakest hand having rank 1.
+/// `input` must already be normalised, see [`common::input::normalise`].
+pub fn total_winnings(input: &str, rules: Rules) -> Result<u64, SolveError> {
+    let mut hands: Vec<(Hand, u64)> = parse_all(input, lines(|line| parse(line, rules)))?;
+
+    hands.sort_by(|(hand1, _), (hand2, _)| hand2.cmp(hand1, rules));
+
+    hands.reverse();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bet))| {
+            let rank = i as u64 + 1;
+
+            bet.checked_mul(rank)
+        })
+        .collect::<Option<Vec<u64>>>()
+        .and_then(checked_sum)
+        .ok_or(SolveError::Overflow("the total winnings"))
+}
+
+/// What a `J` stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rules {
+    /// An ordinary card between `T` and `Q`.
+    Jacks,
+    /// A wildcard that joins whichever group makes the hand strongest, but is
+    /// the weakest card when breaking ties.
+    Jokers,
+}
+
+/// One line of the input: a hand and its bet.
+pub fn parse(input: &str, rules: Rules) -> ParseResult<'_, (Hand<'_>, u64)> {
+    separated_pair(|input| parse_hand(input, rules), space1, number)(input)
+}
+
+pub fn parse_hand(input: &str, rules: Rules) -> ParseResult<'_, Hand<'_>> {
+    let (remaining, raw_hand) = take_while_m_n(5, 5, is_card)
+        .context("five cards")
+        .parse(input)?;
+
+    let hand = Hand::new(raw_hand, rules);
+
+    Ok((remaining, hand))
+}
+
+/// A hand of five cards, from the strongest kind to the weakest.
+#[derive(Debug, PartialEq)]
+pub enum Hand<'a> {
+    FiveOfAKind(&'a str),
+    FourOfAKind(&'a str),
+    FullHouse(&'a str),
+    ThreeOfAKind(&'a str),
+    TwoPair(&'a str),
+    OnePair(&'a str),
+    HighCard(&'a str),
+}
+
+impl<'a> Hand<'a> {
+    pub fn new(raw_hand: &'a str, rules: Rules) -> Hand<'a> {
+        let mut card_counts: HashMap<char, u32> = HashMap::new();
+
+        raw_hand.chars().for_each(|char| {
+            *card_counts.entry(char).or_insert(0) += 1;
+        });
+
+        let num_jokers = match rules {
+            Rules::Jacks => 0,
+            Rules::Jokers => card_counts.remove(&'J').unwrap_or(0),
+        };
+
+        // the jokers join the largest group, and five jokers are a group of their own
+        match card_counts.values_mut().max() {
+            Some(max_count) => *max_count += num_jokers,
+            None => return Hand::FiveOfAKind(raw_hand),
+        }
+
+        match card_counts.len() {
+            1 => Hand::FiveOfAKind(raw_hand),
+            2 => {
+                let max_count = card_counts.values().max().unwrap();
+
+                match *max_count {
+                    4 => Hand::FourOfAKind(raw_hand),
+                    3 => Hand::FullHouse(raw_hand),
+                    _ => Hand::HighCard(raw_hand),
+                }
+            }
+            3 => {
+                let max_count = card_counts.values().max().unwrap();
+
+                match *max_count {
+                    3 => Hand::ThreeOfAKind(raw_hand),
+                    2 => Hand::TwoPair(raw_hand),
+                    _ => Hand::HighCard(raw_hand),
+                }
+            }
+            4 => Hand::OnePair(raw_hand),
+            _ => Hand::HighCard(raw_hand),
+        }
+    }
+
+    /// The cards as they were dealt.
+    pub fn cards(&self) -> &'a str {
+        match *self {
+            Self::FiveOfAKind(val) => val,
+            Self::FullHouse(val) => val,
+            Self::FourOfAKind(val) => val,
+            Self::ThreeOfAKind(val) => val,
+            Self::TwoPair(val) => val,
+            Self::OnePair(val) => val,
+            Self::HighCard(val) => val,
+        }
+    }
+
+    fn strength(&self) -> u8 {
+        match self {
+            Hand::FiveOfAKind(_) => 6,
+            Hand::FourOfAKind(_) => 5,
+            Hand::FullHouse(_) => 4,
+            Hand::ThreeOfAKind(_) => 3,
+            Hand::TwoPair(_) => 2,
+            Hand::OnePair(_) => 1,
+            Hand::HighCard(_) => 0,
+        }
+    }
+
+    /// Orders hands by kind, then card by card from the first.
+    pub fn cmp(&self, other: &Hand, rules: Rules) -> Ordering {
+        match self.strength().cmp(&other.strength()) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => {
+                let self_values = self.cards().as_bytes();
+                let other_values = other.cards().as_bytes();
+
+                for i in 0..self_values.len() {
+                    let self_val = card_value(self_values[i] as char, rules);
+                    let other_val = card_value(other_values[i] as char, rules);
+
+                    if self_val > other_val {
+                        return Ordering::Greater;
+                    } else if self_val < other_val {
+                        return Ordering::Less;
+                    } else {
+                        continue;
+                    }
+                }
+
+                Ordering::Equal
+            }
+        }
+    }
+}
+
+fn is_card(card: char) -> bool {
+    "23456789TJQKA".contains(card)
+}
+
+fn card_value(card: char, rules: Rules) -> u32 {
+    let values: HashMap<char, u32> = HashMap::from([
+        ('2', 2),
+        ('3', 3),
+        ('4', 4),
+        ('5', 5),
+        ('6', 6),
+        ('7', 7),
+        ('8', 8),
+        ('9', 9),
+        ('T', 10),
+        ('J', 11),
+        ('Q', 12),
+        ('K', 13),
+        ('A', 14),
+    ]);
+
+    match (card, rules) {
+        ('J', Rules::Jokers) => 1,
+        _ => *values.get(&card).unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 6440);
+        assert_eq!(part2(EXAMPLE).unwrap(), 5905);
+    }
+
+    #[test]
+    fn reports_broken_assumptions() {
+        let input = "32T3K 765\nT55J 684\nKK6X7 28\n32T3K 220\n";
+
+        assert_eq!(
+            validate(input).to_string(),
+            "line 2: hand \"T55J\" has 4 cards, not 5
+line 3: 'X' in hand \"KK6X7\" is not a card
+line 4: hand \"32T3K\" was dealt on line 1 already"
+        );
+        assert!(matches!(part1(input), Err(SolveError::Violations(_))));
+        assert!(validate(EXAMPLE).is_empty());
+    }
+
+    #[test]
+    fn make_hand() {
+        let jacks = |raw_hand| Hand::new(raw_hand, Rules::Jacks);
+        let jokers = |raw_hand| Hand::new(raw_hand, Rules::Jokers);
+
+        assert_eq!(jacks("32T3K"), Hand::OnePair("32T3K"));
+        assert_eq!(jacks("T55J5"), Hand::ThreeOfAKind("T55J5"));
+        assert_eq!(jacks("KK677"), Hand::TwoPair("KK677"));
+        assert_eq!(jacks("KTJJT"), Hand::TwoPair("KTJJT"));
+        assert_eq!(jacks("QQQJA"), Hand::ThreeOfAKind("QQQJA"));
+        assert_eq!(jacks("99T99"), Hand::FourOfAKind("99T99"));
+        assert_eq!(jacks("KAAAA"), Hand::FourOfAKind("KAAAA"));
+
+        assert_eq!(jokers("32T3K"), Hand::OnePair("32T3K"));
+        assert_eq!(jokers("T55J5"), Hand::FourOfAKind("T55J5"));
+        assert_eq!(jokers("KK677"), Hand::TwoPair("KK677"));
+        assert_eq!(jokers("KTJJT"), Hand::FourOfAKind("KTJJT"));
+        assert_eq!(jokers("QQQJA"), Hand::FourOfAKind("QQQJA"));
+        assert_eq!(jokers("99T99"), Hand::FourOfAKind("99T99"));
+        assert_eq!(jokers("KAAAA"), Hand::FourOfAKind("KAAAA"));
+        assert_eq!(jokers("JJJJJ"), Hand::FiveOfAKind("JJJJJ"));
+    }
+
+    #[test]
+    fn hand_order() {
+        let hand1 = Hand::new("JJ222", Rules::Jokers);
+        let hand2 = Hand::new("JJJ8J", Rules::Jokers);
+        let order = hand2.cmp(&hand1, Rules::Jokers);
+
+        assert_eq!(order, Ordering::Less);
+    }
+}
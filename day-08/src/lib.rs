@@ -0,0 +1,374 @@
+//! Day 8: Haunted Wasteland. Following left/right instructions through a
+//! network of nodes.
+
+use std::collections::{HashMap, HashSet};
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use common::{
+    context::Context,
+    input::normalise,
+    math::lcm,
+    parsing::{lines, parse_all, ParseResult},
+    solve::SolveError,
+    validate::{numbered, Report},
+};
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{alphanumeric1, line_ending, space1},
+    multi::count,
+    sequence::{pair, terminated, tuple},
+};
+use nom_supreme::ParserExt;
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input.txt", "input.txt"];
+
+/// The number of steps from `AAA` to `ZZZ`.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    part1_with(input, &Context::new())
+}
+
+/// The number of steps until walks started from every node ending in `A` are
+/// all on nodes ending in `Z` at once.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    part2_with(input, &Context::new())
+}
+
+/// [`part1`], giving up once `context` is cancelled.
+pub fn part1_with(input: &str, context: &Context) -> Result<usize, SolveError> {
+    let input = normalise(input);
+    validate_normalised(&input, 1).into_result()?;
+    let network = parse_all(&input, parse_network)?;
+
+    network.steps("AAA", |node| node == "ZZZ", context)
+}
+
+/// [`part2`], giving up on every walk once `context` is cancelled and
+/// reporting each walk as it ends.
+pub fn part2_with(input: &str, context: &Context) -> Result<usize, SolveError> {
+    let input = normalise(input);
+    validate_normalised(&input, 2).into_result()?;
+    let network = parse_all(&input, parse_network)?;
+
+    let mut starting_nodes: Vec<&str> = network
+        .nodes
+        .keys()
+        .filter_map(|k| {
+            if k.ends_with("A") {
+                return Some(*k);
+            }
+
+            None
+        })
+        .collect();
+    starting_nodes.sort_unstable();
+
+    context.progress().add_total(starting_nodes.len() as u64);
+
+    // every walk is finished before the first to fail is picked, so that the
+    // error does not depend on which thread got there first
+    let walks: Vec<Result<usize, SolveError>> = starting_nodes
+        .into_par_iter()
+        .map(|starting_node| {
+            let steps = network.steps(starting_node, |node| node.ends_with("Z"), context)?;
+            context.progress().advance(1);
+
+            Ok(steps)
+        })
+        .collect();
+    let all_steps: Vec<usize> = walks.into_iter().collect::<Result<_, _>>()?;
+
+    all_steps
+        .into_iter()
+        .try_fold(1, lcm)
+        .ok_or_else(|| SolveError::Overflow("the number of steps"))
+}
+
+/// What the walks take for granted: every node has exactly one entry, and
+/// for `part` 1 there are `AAA` and `ZZZ`, for `part` 2 nodes ending in `A`.
+/// A walk that cannot reach an end, whichever way the instructions turn,
+/// is reported too rather than left to go round in circles.
+pub fn validate(input: &str, part: u8) -> Report {
+    validate_normalised(&normalise(input), part)
+}
+
+/// [`validate`] for an input that is already normalised, so that solving
+/// does not normalise it twice.
+fn validate_normalised(input: &str, part: u8) -> Report {
+    let mut report = Report::new();
+    let mut entries: HashMap<&str, usize> = HashMap::new();
+    let mut nodes: Vec<(usize, &str, [&str; 2])> = Vec::new();
+
+    for (line, text) in numbered(input).skip(2) {
+        let Ok((key, (left, right))) = parse_all(text, parse_map) else {
+            continue;
+        };
+
+        match entries.get(key) {
+            Some(first) => report.line(line, format!("{key} has an entry on line {first} already")),
+            None => {
+                entries.insert(key, line);
+            }
+        }
+        nodes.push((line, key, [left, right]));
+    }
+
+    for (line, key, neighbours) in &nodes {
+        for neighbour in neighbours {
+            if !entries.contains_key(neighbour) {
+                report.line(
+                    *line,
+                    format!("{key} leads to {neighbour}, which has no entry"),
+                );
+            }
+        }
+    }
+
+    let network: HashMap<&str, [&str; 2]> = nodes
+        .iter()
+        .map(|(_, key, neighbours)| (*key, *neighbours))
+        .collect();
+    let (starts, is_end, end): (Vec<&str>, fn(&str) -> bool, &str) = match part {
+        1 => (vec!["AAA"], |node| node == "ZZZ", "ZZZ"),
+        _ => (
+            nodes
+                .iter()
+                .map(|(_, key, _)| *key)
+                .filter(|key| key.ends_with('A'))
+                .collect(),
+            |node| node.ends_with('Z'),
+            "a node ending in Z",
+        ),
+    };
+
+    let missing: Vec<&str> = match part {
+        1 => ["AAA", "ZZZ"]
+            .into_iter()
+            .filter(|node| !entries.contains_key(node))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for node in &missing {
+        report.input(format!("there is no node {node}"));
+    }
+
+    if part != 1 && starts.is_empty() {
+        report.input("no node ends with A");
+    }
+
+    // a walk without both its ends is reported already
+    if missing.is_empty() {
+        for start in starts {
+            if !reaches(&network, start, is_end) {
+                report.line(
+                    entries[start],
+                    format!("{end} cannot be reached from {start}"),
+                );
+            }
+        }
+    }
+
+    report
+}
+
+/// Whether a walk from `start` could reach a node `is_end` accepts with the
+/// right instructions.
+fn reaches(network: &HashMap<&str, [&str; 2]>, start: &str, is_end: fn(&str) -> bool) -> bool {
+    let mut seen = HashSet::from([start]);
+    let mut to_visit = vec![start];
+
+    while let Some(node) = to_visit.pop() {
+        if is_end(node) {
+            return true;
+        }
+
+        for &neighbour in network.get(node).into_iter().flatten() {
+            if seen.insert(neighbour) {
+                to_visit.push(neighbour);
+            }
+        }
+    }
+
+    false
+}
+
+#[derive(Debug)]
+pub struct Network<'a> {
+    pub instructions: Vec<char>,
+    /// Each node's left and right neighbours.
+    pub nodes: HashMap<&'a str, (&'a str, &'a str)>,
+}
+
+impl Network<'_> {
+    /// Follows the instructions, repeating them as often as needed, from
+    /// `starting_node` to the first node that `is_end` accepts, checking
+    /// `context` for cancellation every few thousand steps.
+    pub fn steps(
+        &self,
+        starting_node: &str,
+        is_end: impl Fn(&str) -> bool,
+        context: &Context,
+    ) -> Result<usize, SolveError> {
+        // after visiting every (node, instruction) pair a walk can only be going round in circles
+        let max_steps = self.nodes.len() * self.instructions.len();
+        let mut num_steps = 0;
+        let mut current_index = 0;
+        let mut current_node = starting_node;
+
+        while !is_end(current_node) {
+            if num_steps > max_steps {
+                return Err(SolveError::invalid(format!(
+                    "the walk from {starting_node} never ends"
+                )));
+            }
+
+            if num_steps.is_multiple_of(4096) {
+                context.check()?;
+            }
+
+            num_steps += 1;
+            let next_step = self.instructions[current_index];
+            let (left, right) = self.nodes.get(current_node).ok_or_else(|| {
+                SolveError::invalid(format!("there is no map entry for {current_node}"))
+            })?;
+
+            current_node = if next_step == 'L' { left } else { right };
+            current_index = (current_index + 1) % self.instructions.len();
+        }
+
+        Ok(num_steps)
+    }
+}
+
+pub fn parse_network(input: &str) -> ParseResult<'_, Network<'_>> {
+    let (remaining, (navigation_instructions, nodes)) =
+        pair(parse_navigation_instructions, lines(parse_map))(input)?;
+
+    Ok((
+        remaining,
+        Network {
+            instructions: navigation_instructions.chars().collect(),
+            nodes: nodes.into_iter().collect(),
+        },
+    ))
+}
+
+fn parse_navigation_instructions(input: &str) -> ParseResult<'_, &str> {
+    terminated(
+        take_while1(|char| char == 'L' || char == 'R').context("L or R instructions"),
+        count(line_ending, 2),
+    )(input)
+}
+
+fn parse_map(input: &str) -> ParseResult<'_, (&str, (&str, &str))> {
+    let (remaining, (key, _, _, _)) = tuple((alphanumeric1, space1, tag("="), space1))(input)?;
+    let (remaining, (_, left, _, _, right, _)) = tuple((
+        tag("("),
+        alphanumeric1,
+        tag(","),
+        space1,
+        alphanumeric1,
+        tag(")"),
+    ))(remaining)?;
+
+    Ok((remaining, (key, (left, right))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART1_EXAMPLE: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const PART2_EXAMPLE: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1_works() {
+        let result = part1(
+            "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+        );
+        assert_eq!(result.unwrap(), 2);
+
+        assert_eq!(part1(PART1_EXAMPLE).unwrap(), 6);
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(PART2_EXAMPLE).unwrap(), 6);
+    }
+
+    #[test]
+    fn stops_once_cancelled() {
+        let context = Context::new();
+        context.cancel();
+
+        let result = part2_with(
+            "L
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11Z)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)",
+            &context,
+        );
+        assert!(matches!(result, Err(SolveError::Cancelled)));
+    }
+
+    #[test]
+    fn reports_broken_assumptions() {
+        let input = "LR
+
+AAA = (BBB, CCC)
+BBB = (BBB, BBB)
+BBB = (AAA, AAA)
+11A = (11A, 11A)";
+
+        assert_eq!(
+            validate(input, 1).to_string(),
+            "line 5: BBB has an entry on line 4 already
+line 3: AAA leads to CCC, which has no entry
+there is no node ZZZ"
+        );
+        assert_eq!(
+            validate(input, 2).to_string(),
+            "line 5: BBB has an entry on line 4 already
+line 3: AAA leads to CCC, which has no entry
+line 3: a node ending in Z cannot be reached from AAA
+line 6: a node ending in Z cannot be reached from 11A"
+        );
+        assert!(matches!(part1(input), Err(SolveError::Violations(_))));
+        assert!(validate(PART1_EXAMPLE, 1).is_empty());
+        assert!(validate(PART2_EXAMPLE, 2).is_empty());
+    }
+
+    #[test]
+    fn reports_each_walk() {
+        let context = Context::new();
+
+        assert_eq!(part2_with(PART2_EXAMPLE, &context).unwrap(), 6);
+        assert_eq!(context.progress().counts(), (2, 2));
+    }
+}
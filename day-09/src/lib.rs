@@ -0,0 +1,139 @@
+//! Day 9: Mirage Maintenance. Extrapolating sensor histories from their
+//! differences.
+
+use std::io::BufRead;
+
+use common::{
+    input::for_each_line,
+    math::checked_sum,
+    parsing::{numbers, parse_all, ParseResult},
+    solve::SolveError,
+};
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input.txt", "input.txt"];
+
+/// Sums the next value of every history.
+pub fn part1(input: &str) -> Result<i32, SolveError> {
+    part1_reader(input.as_bytes())
+}
+
+/// Sums the value before the first of every history.
+pub fn part2(input: &str) -> Result<i32, SolveError> {
+    part2_reader(input.as_bytes())
+}
+
+/// [`part1`] for an input streamed one history at a time.
+pub fn part1_reader(reader: impl BufRead) -> Result<i32, SolveError> {
+    sum_of(reader, History::next_value)
+}
+
+/// [`part2`] for an input streamed one history at a time.
+pub fn part2_reader(reader: impl BufRead) -> Result<i32, SolveError> {
+    sum_of(reader, History::previous_value)
+}
+
+fn sum_of(
+    reader: impl BufRead,
+    extrapolate: fn(&History) -> Option<i32>,
+) -> Result<i32, SolveError> {
+    let mut sum: i32 = 0;
+
+    for_each_line(reader, |line| {
+        let history = parse_all(line, parse_line)?;
+
+        sum = extrapolate(&history)
+            .and_then(|value| sum.checked_add(value))
+            .ok_or(SolveError::Overflow("the extrapolated values"))?;
+        Ok(())
+    })?;
+
+    Ok(sum)
+}
+
+/// The readings of one value over time, never empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    pub readings: Vec<i32>,
+}
+
+impl History {
+    /// The readings followed by their differences, the differences of those
+    /// and so on until a row of zeroes, or a single value, is reached. `None`
+    /// if a difference overflows.
+    pub fn difference_rows(&self) -> Option<Vec<Vec<i32>>> {
+        let mut rows = vec![self.readings.clone()];
+        let mut differences: Vec<i32> = self.readings.clone();
+
+        while !differences.is_empty() && !differences.iter().all(|diff| diff == &0) {
+            differences = difference(differences)?;
+
+            if !differences.is_empty() {
+                rows.push(differences.clone());
+            }
+        }
+
+        Some(rows)
+    }
+
+    pub fn next_value(&self) -> Option<i32> {
+        let last_values = self
+            .difference_rows()?
+            .into_iter()
+            .map(|row| *row.last().unwrap());
+
+        checked_sum(last_values)
+    }
+
+    pub fn previous_value(&self) -> Option<i32> {
+        let first_values: Vec<i32> = self
+            .difference_rows()?
+            .into_iter()
+            .map(|row| *row.first().unwrap())
+            .collect();
+
+        first_values
+            .into_iter()
+            .rev()
+            .try_fold(0, |acc: i32, val| val.checked_sub(acc))
+    }
+}
+
+fn difference(vec: Vec<i32>) -> Option<Vec<i32>> {
+    vec.iter()
+        .zip(vec.iter().skip(1))
+        .map(|(a, b)| b.checked_sub(*a))
+        .collect()
+}
+
+pub fn parse_line(input: &str) -> ParseResult<'_, History> {
+    let (remaining, readings) = numbers(input)?;
+
+    Ok((remaining, History { readings }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 114);
+        assert_eq!(part2(EXAMPLE).unwrap(), 2);
+    }
+
+    #[test]
+    fn streams_a_large_input() {
+        // a hundred thousand histories, made as they are read
+        let copies = 33_334;
+        let lines = EXAMPLE.lines().map(String::from).cycle();
+        let reader = || common::input::reader(lines.clone().take(3 * copies));
+
+        assert_eq!(part1_reader(reader()).unwrap(), 114 * copies as i32);
+        assert_eq!(part2_reader(reader()).unwrap(), 2 * copies as i32);
+    }
+}
@@ -0,0 +1,204 @@
+//! Day 11: Cosmic Expansion. Distances between galaxies in an image whose
+//! empty rows and columns keep growing.
+
+use common::{
+    grid::{Grid, Point},
+    input::normalise,
+    solve::SolveError,
+};
+use tracing::{debug, trace};
+
+/// The input files the `part1` and `part2` binaries read from `src/bin`.
+pub const INPUTS: [&str; 2] = ["input.txt", "input.txt"];
+
+/// The sum of the distances once every empty row and column has doubled.
+pub fn part1(input: &str) -> Result<i64, SolveError> {
+    sum_of_distances(input, 2)
+}
+
+/// The sum of the distances once every empty row and column has become a
+/// million of them.
+pub fn part2(input: &str) -> Result<i64, SolveError> {
+    sum_of_distances(input, 1000000)
+}
+
+/// Sums the distances between every pair of galaxies once each empty row and
+/// column has been replaced by `expansion` empty rows or columns. An
+/// expansion of 0 would have the image shrink instead, so it is refused.
+pub fn sum_of_distances(input: &str, expansion: usize) -> Result<i64, SolveError> {
+    if expansion == 0 {
+        return Err(SolveError::invalid(
+            "empty rows and columns cannot expand to 0",
+        ));
+    }
+
+    let image = parse_image(input)?;
+
+    trace!(?image);
+
+    let galaxies = expanded_galaxies(&image, expansion)
+        .ok_or(SolveError::Overflow("an expanded galaxy's position"))?;
+    let rows = galaxies.iter().map(|galaxy| galaxy.row).collect();
+    let cols = galaxies.iter().map(|galaxy| galaxy.col).collect();
+    let total = i64::try_from(sum_of_gaps(rows) + sum_of_gaps(cols))
+        .map_err(|_| SolveError::Overflow("the sum of the distances"))?;
+
+    debug!(?galaxies);
+
+    Ok(total)
+}
+
+pub fn parse_image(input: &str) -> Result<Grid<DataType>, SolveError> {
+    Ok(Grid::parse(&normalise(input), to_data_type)?)
+}
+
+/// Where the galaxies end up after the expansion, or `None` if a position
+/// does not fit in a `usize`. `expansion` must be at least 1.
+pub fn expanded_galaxies(image: &Grid<DataType>, expansion: usize) -> Option<Vec<Point>> {
+    let empty_rows = find_empty_rows(image);
+    let empty_cols = find_empty_rows(&image.transpose());
+
+    debug!(?empty_rows, ?empty_cols, "expanding");
+
+    image
+        .positions(|cell| matches!(cell, DataType::Galaxy))
+        .map(|galaxy| {
+            let row_offset = empty_rows.iter().filter(|&&row| row < galaxy.row).count();
+            let col_offset = empty_cols.iter().filter(|&&col| col < galaxy.col).count();
+
+            trace!(?galaxy, row_offset, col_offset);
+
+            let expand = |position: usize, offset: usize| {
+                offset
+                    .checked_mul(expansion.checked_sub(1)?)?
+                    .checked_add(position)
+            };
+
+            Some(Point::new(
+                expand(galaxy.row, row_offset)?,
+                expand(galaxy.col, col_offset)?,
+            ))
+        })
+        .collect()
+}
+
+/// Sums `|a - b|` over every pair of `coordinates` without visiting the pairs:
+/// once sorted, each coordinate is the larger one of its pairs with every
+/// coordinate before it.
+fn sum_of_gaps(mut coordinates: Vec<usize>) -> i128 {
+    coordinates.sort_unstable();
+
+    let mut total = 0;
+    let mut preceding = 0;
+
+    for (count, coordinate) in coordinates.into_iter().enumerate() {
+        let coordinate = coordinate as i128;
+
+        total += coordinate * count as i128 - preceding;
+        preceding += coordinate;
+    }
+
+    total
+}
+
+fn find_empty_rows(image: &Grid<DataType>) -> Vec<usize> {
+    image
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| row.iter().all(|cell| matches!(cell, DataType::EmptySpace)))
+        .map(|(row_index, _)| row_index)
+        .collect()
+}
+
+fn to_data_type(input: char) -> Option<DataType> {
+    match input {
+        '#' => Some(DataType::Galaxy),
+        '.' => Some(DataType::EmptySpace),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DataType {
+    EmptySpace,
+    Galaxy,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::differential;
+
+    /// Measures each pair of galaxies on its own, counting the empty rows and
+    /// columns between them.
+    fn pairwise(input: &str, expansion: i64) -> Result<i64, SolveError> {
+        let image = parse_image(input)?;
+        let empty_rows = find_empty_rows(&image);
+        let empty_cols = find_empty_rows(&image.transpose());
+        let galaxies: Vec<Point> = image
+            .positions(|cell| matches!(cell, DataType::Galaxy))
+            .collect();
+        let crossed = |empty: &[usize], a: usize, b: usize| {
+            empty
+                .iter()
+                .filter(|&&line| a.min(b) < line && line < a.max(b))
+                .count() as i64
+        };
+
+        let mut total = 0;
+
+        for (i, a) in galaxies.iter().enumerate() {
+            for b in &galaxies[i + 1..] {
+                let empty = crossed(&empty_rows, a.row, b.row) + crossed(&empty_cols, a.col, b.col);
+
+                total += a.manhattan_distance(b) as i64 + empty * (expansion - 1);
+            }
+        }
+
+        Ok(total)
+    }
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 374);
+        assert_eq!(sum_of_distances(EXAMPLE, 10).unwrap(), 1030);
+        assert_eq!(sum_of_distances(EXAMPLE, 100).unwrap(), 8410);
+    }
+
+    #[test]
+    fn rejects_expansions_that_shrink_or_overflow() {
+        assert!(matches!(
+            sum_of_distances(EXAMPLE, 0),
+            Err(SolveError::Invalid(_))
+        ));
+        assert_eq!(sum_of_distances(EXAMPLE, 1).unwrap(), 292);
+        assert!(matches!(
+            sum_of_distances(EXAMPLE, usize::MAX),
+            Err(SolveError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn agrees_with_pairwise_distances() {
+        for expansion in [2, 10, 1000000] {
+            let reference = |input: &str| pairwise(input, expansion as i64);
+            let optimised = |input: &str| sum_of_distances(input, expansion);
+
+            if let Err(mismatch) = differential::check(11, 1..=12, reference, optimised) {
+                panic!("expanding by {expansion}: {mismatch}");
+            }
+        }
+    }
+}
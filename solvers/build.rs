@@ -0,0 +1,16 @@
+//! Generates the C header for the ABI in `src/ffi.rs` into `OUT_DIR`. The
+//! copy in `include/` is refreshed from it by hand, see `src/ffi.rs`.
+
+use std::{env, path::Path};
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::generate(&crate_dir)
+        .expect("src/ffi.rs has a C header")
+        .write_to_file(Path::new(&out_dir).join("aoc.h"));
+}
@@ -0,0 +1,61 @@
+/* Generated by the solvers build script from src/ffi.rs; do not edit. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Room for any answer, including its terminating NUL.
+#define AOC_ANSWER_CAPACITY 32
+
+// What became of a call. Anything but `AOC_STATUS_OK` comes with a message in
+// the output buffer, truncated to fit.
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  AOC_STATUS_UNKNOWN_PART = 2,
+  // `input` is NULL with a non-zero length, or `out` is NULL.
+  AOC_STATUS_NULL_POINTER = 3,
+  AOC_STATUS_INVALID_UTF8 = 4,
+  // The input is not in the puzzle's format.
+  AOC_STATUS_PARSE_ERROR = 5,
+  // The input parsed, but the solver cannot answer it.
+  AOC_STATUS_UNSUPPORTED_INPUT = 6,
+  AOC_STATUS_OVERFLOW = 7,
+  // The answer does not fit in `out`; nothing but an empty string is written.
+  AOC_STATUS_BUFFER_TOO_SMALL = 8,
+  // The solver panicked. This is a bug.
+  AOC_STATUS_PANIC = 9,
+  // The solver was cancelled. `aoc_solve` never cancels, so this and
+  // `AOC_STATUS_READ_ERROR` are only here to keep the codes in step with the
+  // Rust errors.
+  AOC_STATUS_CANCELLED = 10,
+  // A streamed input could not be read.
+  AOC_STATUS_READ_ERROR = 11,
+} AocStatus;
+
+// Solves `part` of `day` for the `len` bytes of UTF-8 at `input`, and writes
+// the answer into `out` as a NUL terminated decimal number.
+// [`AOC_ANSWER_CAPACITY`] bytes are always enough for the answer.
+//
+// # Safety
+//
+// `input` must point to `len` readable bytes, or may be NULL if `len` is 0.
+// `out` must point to `out_len` writable bytes.
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t len,
+                         char *out,
+                         size_t out_len);
+
+// A static, NUL terminated name for `status`, e.g. `"parse error"`, or
+// `"unknown"` for a number that is no status. It takes a plain `int` since C
+// lets any value through as an `AocStatus`, which Rust must not see.
+const char *aoc_status_name(int status);
+
+#endif  /* AOC_H */
@@ -0,0 +1,174 @@
+//! Every day's worked examples, with the answers the puzzles give for them.
+
+use common::input::variants;
+
+use crate::{solve, DAYS};
+
+/// `(day, part, example, answer)` for every part of every day.
+const EXAMPLES: [(u8, u8, &str, &str); 20] = [
+    (1, 1, DAY_01_PART1, "142"),
+    (1, 2, DAY_01_PART2, "281"),
+    (2, 1, DAY_02, "8"),
+    (2, 2, DAY_02, "2286"),
+    (3, 1, DAY_03, "4361"),
+    (3, 2, DAY_03, "467835"),
+    (4, 1, DAY_04, "13"),
+    (4, 2, DAY_04, "30"),
+    (5, 1, DAY_05, "35"),
+    (5, 2, DAY_05, "46"),
+    (6, 1, DAY_06, "288"),
+    (6, 2, DAY_06, "71503"),
+    (7, 1, DAY_07, "6440"),
+    (7, 2, DAY_07, "5905"),
+    (8, 1, DAY_08_PART1, "6"),
+    (8, 2, DAY_08_PART2, "6"),
+    (9, 1, DAY_09, "114"),
+    (9, 2, DAY_09, "2"),
+    (11, 1, DAY_11, "374"),
+    (11, 2, DAY_11, "82000210"),
+];
+
+const DAY_01_PART1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+const DAY_01_PART2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+const DAY_02: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+const DAY_03: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+const DAY_04: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+const DAY_05: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+
+";
+
+const DAY_06: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+const DAY_07: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+const DAY_08_PART1: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+const DAY_08_PART2: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+const DAY_09: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+const DAY_11: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+#[test]
+fn covers_every_part() {
+    for day in DAYS {
+        for part in 1..=2 {
+            assert!(
+                EXAMPLES
+                    .iter()
+                    .any(|example| (example.0, example.1) == (day, part)),
+                "day {day} part {part} has no example"
+            );
+        }
+    }
+}
+
+#[test]
+fn accepts_any_line_endings_and_trailing_whitespace() {
+    for (day, part, example, answer) in EXAMPLES {
+        for (variant, input) in variants(example) {
+            let solved = solve(day, part, &input);
+
+            assert_eq!(
+                solved.as_deref().ok(),
+                Some(answer),
+                "day {day} part {part}, {variant}: {solved:?}"
+            );
+        }
+    }
+}
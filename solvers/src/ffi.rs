@@ -0,0 +1,291 @@
+//! The C ABI. Every function returns an [`AocStatus`] rather than panicking
+//! across the boundary, and writes its text into a buffer the caller owns, so
+//! there is nothing to free.
+//!
+//! `include/aoc.h` is generated from this module by the build script, see
+//! `the_header_is_up_to_date` for how to refresh the committed copy.
+
+use std::{
+    ffi::{c_char, c_int},
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use common::solve::SolveError;
+
+use crate::Error;
+
+/// Room for any answer, including its terminating NUL.
+pub const AOC_ANSWER_CAPACITY: usize = 32;
+
+/// What became of a call. Anything but `AOC_STATUS_OK` comes with a message in
+/// the output buffer, truncated to fit.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    /// `input` is NULL with a non-zero length, or `out` is NULL.
+    NullPointer = 3,
+    InvalidUtf8 = 4,
+    /// The input is not in the puzzle's format.
+    ParseError = 5,
+    /// The input parsed, but the solver cannot answer it.
+    UnsupportedInput = 6,
+    Overflow = 7,
+    /// The answer does not fit in `out`; nothing but an empty string is written.
+    BufferTooSmall = 8,
+    /// The solver panicked. This is a bug.
+    Panic = 9,
+    /// The solver was cancelled. `aoc_solve` never cancels, so this and
+    /// `AOC_STATUS_READ_ERROR` are only here to keep the codes in step with the
+    /// Rust errors.
+    Cancelled = 10,
+    /// A streamed input could not be read.
+    ReadError = 11,
+}
+
+impl From<&Error> for AocStatus {
+    fn from(error: &Error) -> Self {
+        match error {
+            Error::UnknownDay(_) => AocStatus::UnknownDay,
+            Error::UnknownPart(_) => AocStatus::UnknownPart,
+            Error::Solve(SolveError::Parse(_) | SolveError::Grid(_)) => AocStatus::ParseError,
+            Error::Solve(SolveError::Invalid(_) | SolveError::Violations(_)) => {
+                AocStatus::UnsupportedInput
+            }
+            Error::Solve(SolveError::Overflow(_)) => AocStatus::Overflow,
+            Error::Solve(SolveError::Cancelled) => AocStatus::Cancelled,
+            Error::Solve(SolveError::Read(_)) => AocStatus::ReadError,
+        }
+    }
+}
+
+/// Solves `part` of `day` for the `len` bytes of UTF-8 at `input`, and writes
+/// the answer into `out` as a NUL terminated decimal number.
+/// [`AOC_ANSWER_CAPACITY`] bytes are always enough for the answer.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, or may be NULL if `len` is 0.
+/// `out` must point to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> AocStatus {
+    if out.is_null() {
+        return AocStatus::NullPointer;
+    }
+
+    // SAFETY: the caller guarantees `out` points to `out_len` writable bytes
+    let out = unsafe { slice::from_raw_parts_mut(out.cast::<u8>(), out_len) };
+
+    let input: &[u8] = match (input.is_null(), len) {
+        (_, 0) => &[],
+        (true, _) => return fail(out, AocStatus::NullPointer, "the input is NULL"),
+        // SAFETY: the caller guarantees `input` points to `len` readable bytes
+        (false, _) => unsafe { slice::from_raw_parts(input, len) },
+    };
+
+    let Ok(input) = std::str::from_utf8(input) else {
+        return fail(out, AocStatus::InvalidUtf8, "the input is not UTF-8");
+    };
+
+    match panic::catch_unwind(AssertUnwindSafe(|| crate::solve(day, part, input))) {
+        Ok(Ok(answer)) if answer.len() < out.len() => {
+            write(out, &answer);
+            AocStatus::Ok
+        }
+        Ok(Ok(_)) => fail(out, AocStatus::BufferTooSmall, ""),
+        Ok(Err(error)) => fail(out, AocStatus::from(&error), &error.to_string()),
+        Err(_) => fail(out, AocStatus::Panic, "the solver panicked"),
+    }
+}
+
+/// A static, NUL terminated name for `status`, e.g. `"parse error"`, or
+/// `"unknown"` for a number that is no status. It takes a plain `int` since C
+/// lets any value through as an `AocStatus`, which Rust must not see.
+#[no_mangle]
+pub extern "C" fn aoc_status_name(status: c_int) -> *const c_char {
+    let name: &'static [u8] = match AocStatus::from_code(status) {
+        Some(AocStatus::Ok) => b"ok\0",
+        Some(AocStatus::UnknownDay) => b"unknown day\0",
+        Some(AocStatus::UnknownPart) => b"unknown part\0",
+        Some(AocStatus::NullPointer) => b"null pointer\0",
+        Some(AocStatus::InvalidUtf8) => b"invalid UTF-8\0",
+        Some(AocStatus::ParseError) => b"parse error\0",
+        Some(AocStatus::UnsupportedInput) => b"unsupported input\0",
+        Some(AocStatus::Overflow) => b"overflow\0",
+        Some(AocStatus::BufferTooSmall) => b"buffer too small\0",
+        Some(AocStatus::Panic) => b"panic\0",
+        Some(AocStatus::Cancelled) => b"cancelled\0",
+        Some(AocStatus::ReadError) => b"read error\0",
+        None => b"unknown\0",
+    };
+
+    name.as_ptr().cast()
+}
+
+impl AocStatus {
+    fn from_code(code: c_int) -> Option<AocStatus> {
+        let status = match code {
+            0 => AocStatus::Ok,
+            1 => AocStatus::UnknownDay,
+            2 => AocStatus::UnknownPart,
+            3 => AocStatus::NullPointer,
+            4 => AocStatus::InvalidUtf8,
+            5 => AocStatus::ParseError,
+            6 => AocStatus::UnsupportedInput,
+            7 => AocStatus::Overflow,
+            8 => AocStatus::BufferTooSmall,
+            9 => AocStatus::Panic,
+            10 => AocStatus::Cancelled,
+            11 => AocStatus::ReadError,
+            _ => return None,
+        };
+
+        Some(status)
+    }
+}
+
+fn fail(out: &mut [u8], status: AocStatus, message: &str) -> AocStatus {
+    write(out, message);
+    status
+}
+
+/// Copies as much of `text` as fits into `out`, without splitting a
+/// character, and terminates it.
+fn write(out: &mut [u8], text: &str) {
+    let Some(room) = out.len().checked_sub(1) else {
+        return;
+    };
+
+    let mut end = text.len().min(room);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    out[..end].copy_from_slice(&text.as_bytes()[..end]);
+    out[end] = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr};
+
+    use super::*;
+
+    fn call(day: u8, part: u8, input: &[u8], out_len: usize) -> (AocStatus, String) {
+        let mut out = vec![1 as c_char; out_len];
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out.as_mut_ptr(),
+                out_len,
+            )
+        };
+        let text = match out_len {
+            0 => String::new(),
+            _ => unsafe { CStr::from_ptr(out.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
+        };
+
+        (status, text)
+    }
+
+    #[test]
+    fn writes_the_answer() {
+        let input = b"Time:      7  15   30\nDistance:  9  40  200\n";
+
+        assert_eq!(
+            call(6, 1, input, AOC_ANSWER_CAPACITY),
+            (AocStatus::Ok, "288".into())
+        );
+        assert_eq!(call(6, 2, input, 6), (AocStatus::Ok, "71503".into()));
+        assert_eq!(call(6, 2, input, 5), (AocStatus::BufferTooSmall, "".into()));
+    }
+
+    #[test]
+    fn reports_errors_with_a_message() {
+        let (status, message) = call(7, 1, b"AAAAA x\n", 64);
+        assert_eq!(status, AocStatus::ParseError);
+        assert!(
+            message.starts_with("could not parse the input"),
+            "{message}"
+        );
+
+        assert_eq!(call(10, 1, b"", 64).0, AocStatus::UnknownDay);
+        assert_eq!(call(1, 0, b"", 64).0, AocStatus::UnknownPart);
+        assert_eq!(call(1, 1, b"\xff", 64).0, AocStatus::InvalidUtf8);
+        assert_eq!(
+            call(1, 1, b"abc", 10),
+            (AocStatus::UnsupportedInput, "unsupport".into())
+        );
+        assert_eq!(call(1, 1, b"abc", 0).0, AocStatus::UnsupportedInput);
+    }
+
+    #[test]
+    fn rejects_null_pointers() {
+        let mut out = [0 as c_char; 8];
+        let status = unsafe { aoc_solve(1, 1, ptr::null(), 3, out.as_mut_ptr(), out.len()) };
+        assert_eq!(status, AocStatus::NullPointer);
+
+        let status = unsafe { aoc_solve(1, 1, b"1".as_ptr(), 1, ptr::null_mut(), 8) };
+        assert_eq!(status, AocStatus::NullPointer);
+    }
+
+    #[test]
+    fn names_every_status_and_no_other() {
+        let name = |code| {
+            unsafe { CStr::from_ptr(aoc_status_name(code)) }
+                .to_str()
+                .unwrap()
+        };
+
+        for code in 0..=11 {
+            let status = AocStatus::from_code(code).unwrap();
+            assert_eq!(status as c_int, code);
+            assert_ne!(name(code), "unknown");
+        }
+
+        assert_eq!(name(AocStatus::ParseError as c_int), "parse error");
+        assert_eq!(name(12), "unknown");
+        assert_eq!(name(-1), "unknown");
+    }
+
+    /// The committed header is what C callers build against, so it must match
+    /// the one the build script generates. Run with `AOC_UPDATE_HEADER=1` to
+    /// refresh it after changing the ABI.
+    #[test]
+    fn the_header_is_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/aoc.h"));
+        let committed = concat!(env!("CARGO_MANIFEST_DIR"), "/include/aoc.h");
+
+        if std::env::var_os("AOC_UPDATE_HEADER").is_some() {
+            std::fs::write(committed, generated).unwrap();
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(committed).unwrap(),
+            generated,
+            "include/aoc.h is stale, run the tests with AOC_UPDATE_HEADER=1"
+        );
+    }
+
+    #[test]
+    fn truncates_on_a_character_boundary() {
+        let mut out = [0xff; 3];
+        write(&mut out, "añb");
+        assert_eq!(out, [b'a', 0, 0xff]);
+    }
+}
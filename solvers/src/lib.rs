@@ -0,0 +1,214 @@
+//! Every day's solvers behind a single entry point, for Rust callers and,
+//! through the C ABI in [`ffi`], for tooling written in other languages.
+
+use std::fmt::Display;
+
+use common::{context::Context, solve::SolveError, validate::Report};
+
+#[cfg(test)]
+mod examples;
+pub mod ffi;
+
+/// The days that have solvers.
+pub const DAYS: [u8; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 11];
+
+/// Why a day and part could not be solved.
+#[derive(Debug)]
+pub enum Error {
+    UnknownDay(u8),
+    UnknownPart(u8),
+    Solve(SolveError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownDay(day) => write!(f, "there is no solver for day {day}"),
+            Error::UnknownPart(part) => write!(f, "there is no part {part}"),
+            Error::Solve(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SolveError> for Error {
+    fn from(error: SolveError) -> Self {
+        Error::Solve(error)
+    }
+}
+
+/// The file in `day`'s `src/bin` directory that its `partN` binary reads, as
+/// the day's `INPUTS` names it.
+pub fn input_file(day: u8, part: u8) -> Option<&'static str> {
+    let inputs = match day {
+        1 => day_01::INPUTS,
+        2 => day_02::INPUTS,
+        3 => day_03::INPUTS,
+        4 => day_04::INPUTS,
+        5 => day_05::INPUTS,
+        6 => day_06::INPUTS,
+        7 => day_07::INPUTS,
+        8 => day_08::INPUTS,
+        9 => day_09::INPUTS,
+        11 => day_11::INPUTS,
+        _ => return None,
+    };
+
+    inputs.get(usize::from(part).checked_sub(1)?).copied()
+}
+
+/// Solves `part` of `day` for `input`, with the answer formatted as the part
+/// binaries print it.
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, Error> {
+    solve_with(day, part, input, &Context::new())
+}
+
+/// Like [`solve`], but the solvers that can stop early give up once `context`
+/// is cancelled, and the slow ones report their progress into it. The others
+/// run to the end regardless, reporting nothing.
+pub fn solve_with(day: u8, part: u8, input: &str, context: &Context) -> Result<String, Error> {
+    if !DAYS.contains(&day) {
+        return Err(Error::UnknownDay(day));
+    }
+
+    match (day, part) {
+        (1, 1) => answer(day_01::part1(input)),
+        (1, 2) => answer(day_01::part2(input)),
+        (2, 1) => answer(day_02::part1(input)),
+        (2, 2) => answer(day_02::part2(input)),
+        (3, 1) => answer(day_03::part1(input)),
+        (3, 2) => answer(day_03::part2(input)),
+        (4, 1) => answer(day_04::part1(input)),
+        (4, 2) => answer(day_04::part2(input)),
+        (5, 1) => answer(day_05::part1(input)),
+        (5, 2) => answer(day_05::part2_with(input, context)),
+        (6, 1) => answer(day_06::part1(input)),
+        (6, 2) => answer(day_06::part2(input)),
+        (7, 1) => answer(day_07::part1(input)),
+        (7, 2) => answer(day_07::part2(input)),
+        (8, 1) => answer(day_08::part1_with(input, context)),
+        (8, 2) => answer(day_08::part2_with(input, context)),
+        (9, 1) => answer(day_09::part1(input)),
+        (9, 2) => answer(day_09::part2(input)),
+        (11, 1) => answer(day_11::part1(input)),
+        (11, 2) => answer(day_11::part2(input)),
+        _ => Err(Error::UnknownPart(part)),
+    }
+}
+
+/// The assumptions `input` breaks that `part` of `day` checks before
+/// solving, see [`common::validate`]. Days that check nothing give an empty
+/// report.
+pub fn validate(day: u8, part: u8, input: &str) -> Result<Report, Error> {
+    if !DAYS.contains(&day) {
+        return Err(Error::UnknownDay(day));
+    }
+
+    if !(1..=2).contains(&part) {
+        return Err(Error::UnknownPart(part));
+    }
+
+    let report = match day {
+        4 => day_04::validate(input, part),
+        7 => day_07::validate(input),
+        8 => day_08::validate(input, part),
+        _ => Report::new(),
+    };
+
+    Ok(report)
+}
+
+fn answer(answer: Result<impl Display, SolveError>) -> Result<String, Error> {
+    Ok(answer?.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, sample};
+
+    use super::*;
+
+    #[test]
+    fn dispatches_to_the_day() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+        assert_eq!(solve(1, 1, input).unwrap(), "142");
+        assert!(matches!(solve(10, 1, input), Err(Error::UnknownDay(10))));
+        assert!(matches!(solve(1, 3, input), Err(Error::UnknownPart(3))));
+        assert!(matches!(solve(8, 1, "LR"), Err(Error::Solve(_))));
+    }
+
+    #[test]
+    fn names_every_days_inputs() {
+        for day in DAYS {
+            assert!(input_file(day, 1).is_some(), "day {day}");
+            assert!(input_file(day, 2).is_some(), "day {day}");
+        }
+
+        assert_eq!(input_file(2, 2), Some("input1.txt"));
+        assert_eq!(input_file(7, 1), Some("input.txt"));
+        assert_eq!(input_file(1, 0), None);
+        assert_eq!(input_file(1, 3), None);
+        assert_eq!(input_file(10, 1), None);
+    }
+
+    #[test]
+    fn validates_before_solving() {
+        let input = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n";
+
+        assert_eq!(
+            validate(8, 1, input).unwrap().to_string(),
+            "there is no node ZZZ"
+        );
+        assert!(validate(8, 2, input)
+            .unwrap()
+            .to_string()
+            .contains("cannot be reached from AAA"));
+        assert!(validate(6, 1, input).unwrap().is_empty());
+        assert!(matches!(validate(10, 1, input), Err(Error::UnknownDay(10))));
+        assert!(matches!(
+            solve(8, 1, input),
+            Err(Error::Solve(SolveError::Violations(_)))
+        ));
+    }
+
+    proptest! {
+        /// What every day's generator makes, its solvers accept: both parts
+        /// answer, or refuse for a reason the generator does not promise to
+        /// rule out, like a day 8 `ZZZ` that cannot be reached, but never fail
+        /// to parse. An input is refused with a report exactly when validating
+        /// it finds one, and the answer does not change with line endings or
+        /// trailing whitespace.
+        #[test]
+        fn solves_generated_inputs(
+            day in sample::select(DAYS.to_vec()),
+            seed in any::<u64>(),
+            size in 1_usize..10,
+        ) {
+            let input = common::generate::input(day, seed, size).unwrap();
+
+            for part in 1..=2 {
+                let answer = solve(day, part, &input);
+                let report = validate(day, part, &input).unwrap();
+
+                match &answer {
+                    Ok(_) | Err(Error::Solve(SolveError::Invalid(_) | SolveError::Overflow(_))) => {
+                        prop_assert!(report.is_empty(), "day {day} part {part}: {report}");
+                    }
+                    Err(Error::Solve(SolveError::Violations(violations))) => {
+                        prop_assert_eq!(violations, &report);
+                    }
+                    Err(error) => prop_assert!(false, "day {day} part {part}: {error}\n{input}"),
+                }
+
+                let answer = answer.map_err(|error| error.to_string());
+                for (variant, input) in common::input::variants(&input) {
+                    let mangled = solve(day, part, &input).map_err(|error| error.to_string());
+
+                    prop_assert_eq!(&mangled, &answer, "day {} part {}, {}", day, part, variant);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/* Exercises the C ABI the way other languages' tooling calls it. Built and run
+ * by tests/c_program.rs. */
+
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char EXAMPLE[] = "Time:      7  15   30\n"
+                              "Distance:  9  40  200\n";
+
+static AocStatus solve(uint8_t day, uint8_t part, const char *input, char *out, size_t out_len) {
+    return aoc_solve(day, part, (const uint8_t *)input, strlen(input), out, out_len);
+}
+
+int main(void) {
+    char out[AOC_ANSWER_CAPACITY];
+    char message[256];
+
+    assert(solve(6, 1, EXAMPLE, out, sizeof out) == AOC_STATUS_OK);
+    assert(strcmp(out, "288") == 0);
+
+    assert(solve(6, 2, EXAMPLE, out, sizeof out) == AOC_STATUS_OK);
+    assert(strcmp(out, "71503") == 0);
+
+    assert(solve(6, 2, EXAMPLE, out, 3) == AOC_STATUS_BUFFER_TOO_SMALL);
+    assert(strcmp(out, "") == 0);
+
+    assert(solve(10, 1, EXAMPLE, message, sizeof message) == AOC_STATUS_UNKNOWN_DAY);
+    assert(strcmp(message, "there is no solver for day 10") == 0);
+
+    assert(solve(6, 3, EXAMPLE, message, sizeof message) == AOC_STATUS_UNKNOWN_PART);
+    assert(solve(7, 1, "AAAAA x\n", message, sizeof message) == AOC_STATUS_PARSE_ERROR);
+    assert(aoc_solve(1, 1, NULL, 4, message, sizeof message) == AOC_STATUS_NULL_POINTER);
+    assert(aoc_solve(1, 1, (const uint8_t *)"\xff", 1, message, sizeof message) ==
+           AOC_STATUS_INVALID_UTF8);
+
+    assert(strcmp(aoc_status_name(AOC_STATUS_PARSE_ERROR), "parse error") == 0);
+    assert(strcmp(aoc_status_name(42), "unknown") == 0);
+
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,52 @@
+//! Compiles `tests/c/solve.c` against the shared library and runs it.
+
+use std::{env, path::PathBuf, process::Command};
+
+#[test]
+fn c_program_passes() {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // the test binary lives in target/debug/deps
+    let profile = env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("solve");
+
+    // integration tests only link the rlib, so the cdylib may not be built yet
+    let built = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--manifest-path"])
+        .arg(manifest.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", profile.parent().unwrap())
+        .status()
+        .unwrap();
+    assert!(built.success());
+
+    let compiled = Command::new(env::var("CC").unwrap_or("cc".into()))
+        .arg(manifest.join("tests/c/solve.c"))
+        .arg("-I")
+        .arg(manifest.join("include"))
+        .arg("-L")
+        .arg(&profile)
+        .arg("-lsolvers")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .expect("a C compiler is installed");
+    assert!(compiled.success());
+
+    let output = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &profile)
+        .env("DYLD_LIBRARY_PATH", &profile)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}